@@ -0,0 +1,163 @@
+//! Finding where a simulation starts repeating itself, to skip ahead to a
+//! step too far away to run to.
+//!
+//! States are compared by a key the caller extracts, usually the part of the
+//! state that decides everything after it. Keys are stored whole and compared
+//! for equality, so two different states never pass for the same one just
+//! because they hash alike.
+//!
+//! ```
+//! use aoc_common::cycle::extrapolate;
+//!
+//! // a counter that wraps at 10 and a running total of its values
+//! let total = extrapolate(
+//!     (0, 0),
+//!     |(n, sum)| {
+//!         *n = (*n + 1) % 10;
+//!         *sum += *n;
+//!     },
+//!     |(n, _)| *n,
+//!     |(_, sum)| *sum,
+//!     1_000_000,
+//! );
+//! assert_eq!(total, 4_500_000);
+//! ```
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+/// The states from step `start` on repeat every `period` steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub period: usize,
+}
+
+/// Steps `state` until its key repeats, giving up after `limit` steps.
+pub fn find_cycle<S, K: Eq + Hash>(
+    mut state: S,
+    step: impl FnMut(&mut S),
+    key: impl FnMut(&S) -> K,
+    limit: usize,
+) -> Option<Cycle> {
+    detect(&mut state, step, key, limit, |_| ())
+}
+
+/// The `metric` of `state` after `target` steps, running only until the
+/// states repeat. The metric must grow by the same amount on each turn of the
+/// cycle, as a height or a total does.
+pub fn extrapolate<S, K: Eq + Hash>(
+    state: S,
+    step: impl FnMut(&mut S),
+    key: impl FnMut(&S) -> K,
+    metric: impl FnMut(&S) -> i64,
+    target: usize,
+) -> i64 {
+    extrapolate_within(state, step, key, metric, target, target)
+        .expect("running all the way to the target needs no cycle")
+}
+
+/// As [`extrapolate`], giving up with `None` if the states haven't repeated
+/// within `limit` steps, for a simulation that might never repeat or only
+/// after too long.
+pub fn extrapolate_within<S, K: Eq + Hash>(
+    mut state: S,
+    step: impl FnMut(&mut S),
+    key: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> i64,
+    target: usize,
+    limit: usize,
+) -> Option<i64> {
+    // the metric at every step so far
+    let mut metrics = vec![];
+    let limit = limit.min(target);
+    let cycle = detect(&mut state, step, key, limit, |s| metrics.push(metric(s)));
+
+    match cycle {
+        Some(Cycle { start, period }) if target >= metrics.len() => {
+            let per_cycle = metrics[start + period] - metrics[start];
+            let cycles = (target - start) / period;
+            let rest = (target - start) % period;
+            Some(metrics[start + rest] + per_cycle * cycles as i64)
+        }
+        _ => metrics.get(target).copied(),
+    }
+}
+
+fn detect<S, K: Eq + Hash>(
+    state: &mut S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    limit: usize,
+    mut visit: impl FnMut(&S),
+) -> Option<Cycle> {
+    let mut seen: HashMap<K, usize> = HashMap::new();
+
+    for i in 0..=limit {
+        visit(state);
+        match seen.entry(key(state)) {
+            Entry::Occupied(e) => {
+                let start = *e.get();
+                return Some(Cycle {
+                    start,
+                    period: i - start,
+                });
+            }
+            Entry::Vacant(e) => {
+                e.insert(i);
+            }
+        }
+
+        if i < limit {
+            step(state);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 3, 4, 5, 6, 7, 8 and back to 4
+    fn next(n: &mut u32) {
+        *n = if *n == 8 { 4 } else { *n + 1 };
+    }
+
+    #[test]
+    fn test_cycle() {
+        assert_eq!(
+            find_cycle(3, next, |n| *n, 100),
+            Some(Cycle {
+                start: 1,
+                period: 5
+            })
+        );
+        assert_eq!(find_cycle(3, next, |n| *n, 4), None);
+
+        // counting the steps, which the key ignores
+        let steps = |target| {
+            extrapolate(
+                (3, 0),
+                |(n, i)| {
+                    next(n);
+                    *i += 1;
+                },
+                |(n, _)| *n,
+                |(_, i)| *i,
+                target,
+            )
+        };
+        assert_eq!(steps(3), 3);
+        assert_eq!(steps(1_000), 1_000);
+
+        // a count that never repeats, and one that repeats too late
+        let count = |limit| extrapolate_within(0, |n| *n += 1, |n| *n, |n| *n, 1_000, limit);
+        assert_eq!(count(10), None);
+        assert_eq!(count(1_000), Some(1_000));
+        let late = extrapolate_within(3, next, |n| *n, |n| *n as i64, 1_000, 4);
+        assert_eq!(late, None);
+    }
+}
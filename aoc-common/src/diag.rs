@@ -0,0 +1,78 @@
+//! Diagnostics: what a solution wants to say about its working, kept apart
+//! from the answers.
+//!
+//! Everything goes to stderr, and only at the verbosity the command line asked
+//! for: `-v` shows [`debug!`](crate::debug) messages, `-vv` the
+//! [`trace!`](crate::trace) dumps of whole data structures as well. Only
+//! [`warn!`](crate::warn), for input that leaves an answer missing or in
+//! doubt, is shown at any verbosity. Stdout is left to the answers, so scripts
+//! can read them whatever the verbosity.
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_verbosity(level: u8) {
+    VERBOSITY.store(level, Ordering::Relaxed);
+}
+
+pub fn verbosity() -> u8 {
+    VERBOSITY.load(Ordering::Relaxed)
+}
+
+/// Whether messages at `level` are shown: 1 for debug, 2 for trace.
+pub fn enabled(level: u8) -> bool {
+    verbosity() >= level
+}
+
+/// Prints to stderr whatever the verbosity, for a problem with the input that
+/// the answers alone wouldn't show.
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => {
+        eprintln!("warning: {}", format_args!($($arg)*));
+    };
+}
+
+/// Prints to stderr when run with `-v` or more.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::diag::enabled(1) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+/// Prints to stderr when run with `-vv`, for dumps too big to want often.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::diag::enabled(2) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Puts the verbosity back when dropped, even if an assertion fails
+    /// first, so the other tests don't start printing.
+    struct Restore(u8);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            set_verbosity(self.0);
+        }
+    }
+
+    #[test]
+    fn test_verbosity() {
+        let _restore = Restore(verbosity());
+        set_verbosity(0);
+        assert!(!enabled(1));
+        set_verbosity(1);
+        assert!(enabled(1) && !enabled(2));
+    }
+}
@@ -0,0 +1,286 @@
+//! Points and directions on the puzzle boards.
+//!
+//! `x` is the column and `y` the row, growing rightwards and downwards as the
+//! puzzle text is laid out, so [`Direction::Up`] is a step to a smaller `y`.
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::ParseError;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+macro_rules! impl_ops {
+    ($point:ident { $($f:ident),* }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $($f: self.$f + rhs.$f),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($f: self.$f - rhs.$f),* }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                Self { $($f: self.$f * rhs),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($f: -self.$f),* }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$f += rhs.$f;)*
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$f -= rhs.$f;)*
+            }
+        }
+    };
+}
+
+impl_ops!(Point2 { x, y });
+impl_ops!(Point3 { x, y, z });
+
+macro_rules! impl_signed {
+    ($($t:ty),*) => {
+        $(
+            impl Point2<$t> {
+                pub fn manhattan(self, other: Self) -> $t {
+                    (self.x - other.x).abs() + (self.y - other.y).abs()
+                }
+
+                pub fn chebyshev(self, other: Self) -> $t {
+                    (self.x - other.x).abs().max((self.y - other.y).abs())
+                }
+
+                /// Each coordinate reduced to -1, 0 or 1, a single step
+                /// towards wherever the point points.
+                pub fn signum(self) -> Self {
+                    Self::new(self.x.signum(), self.y.signum())
+                }
+
+                /// Up, down, left and right of the point.
+                pub fn neighbours4(self) -> [Self; 4] {
+                    Direction::ALL.map(|d| self + d.offset())
+                }
+
+                /// The orthogonal neighbours followed by the diagonals.
+                pub fn neighbours8(self) -> [Self; 8] {
+                    let [up, down, left, right] = self.neighbours4();
+                    [
+                        up,
+                        down,
+                        left,
+                        right,
+                        Self::new(left.x, up.y),
+                        Self::new(right.x, up.y),
+                        Self::new(left.x, down.y),
+                        Self::new(right.x, down.y),
+                    ]
+                }
+            }
+
+            impl Point3<$t> {
+                pub fn manhattan(self, other: Self) -> $t {
+                    (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+                }
+
+                pub fn chebyshev(self, other: Self) -> $t {
+                    (self.x - other.x)
+                        .abs()
+                        .max((self.y - other.y).abs())
+                        .max((self.z - other.z).abs())
+                }
+
+                /// The six points sharing a face with this one.
+                pub fn neighbours6(self) -> [Self; 6] {
+                    let Self { x, y, z } = self;
+                    [
+                        Self::new(x - 1, y, z),
+                        Self::new(x + 1, y, z),
+                        Self::new(x, y - 1, z),
+                        Self::new(x, y + 1, z),
+                        Self::new(x, y, z - 1),
+                        Self::new(x, y, z + 1),
+                    ]
+                }
+            }
+
+            impl From<Direction> for Point2<$t> {
+                fn from(d: Direction) -> Self {
+                    match d {
+                        Direction::Up => Self::new(0, -1),
+                        Direction::Down => Self::new(0, 1),
+                        Direction::Left => Self::new(-1, 0),
+                        Direction::Right => Self::new(1, 0),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_signed!(i32, i64, isize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// The step this direction takes, in whichever signed type is wanted.
+    pub fn offset<T>(self) -> Point2<T>
+    where
+        Point2<T>: From<Direction>,
+    {
+        self.into()
+    }
+
+    /// A quarter turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    /// A quarter turn clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+/// One of the arrows `^ v < >`, or the letters `U D L R`. The error points at
+/// `s`, so [`parse_field`](crate::parse_field) places it in the line it came
+/// from.
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "^" | "U" => Ok(Direction::Up),
+            "v" | "D" => Ok(Direction::Down),
+            "<" | "L" => Ok(Direction::Left),
+            ">" | "R" => Ok(Direction::Right),
+            _ => Err(ParseError::new(s, s, "a direction, U, D, L or R")),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Up => write!(f, "^"),
+            Direction::Down => write!(f, "v"),
+            Direction::Left => write!(f, "<"),
+            Direction::Right => write!(f, ">"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point2() {
+        let p = Point2::new(3i64, -2);
+        assert_eq!(p + Point2::new(1, 1), Point2::new(4, -1));
+        assert_eq!(p - Point2::new(1, 1), Point2::new(2, -3));
+        assert_eq!(p * 2, Point2::new(6, -4));
+        assert_eq!(p.manhattan(Point2::default()), 5);
+        assert_eq!(p.chebyshev(Point2::default()), 3);
+        assert_eq!(p.neighbours4()[0], Point2::new(3, -3));
+        assert!(p
+            .neighbours8()
+            .iter()
+            .all(|n| n.chebyshev(p) == 1 && *n != p));
+    }
+
+    #[test]
+    fn test_direction() {
+        for d in Direction::ALL {
+            assert_eq!(d.turn_left().turn_right(), d);
+            assert_eq!(d.turn_right().turn_right(), d.reverse());
+            assert_eq!(d.offset::<i32>() + d.reverse().offset(), Point2::default());
+        }
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.offset::<i64>(), Point2::new(0, -1));
+
+        assert_eq!("<".parse(), Ok(Direction::Left));
+        let line = "R 4\nX 2";
+        let err = crate::parse_field::<Direction>(line, &line[4..5], "a direction").unwrap_err();
+        assert_eq!((err.line, err.column, err.text.as_str()), (2, 1, "X"));
+    }
+}
@@ -0,0 +1,386 @@
+//! Two dimensional boards. [`Grid`] is a dense, fixed size board parsed from a
+//! char map; [`SparseGrid`] holds only the occupied cells of a board with no
+//! fixed edges. Both are addressed by [`Point2`], `x` being the column.
+use std::{
+    collections::HashMap,
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{geometry::Point2, ParseError};
+
+/// A cell of a [`Grid`], counted from the top left.
+pub type Pos = Point2<usize>;
+
+/// A cell of a [`SparseGrid`]. Either coordinate may be negative.
+pub type Coord = Point2<i64>;
+
+/// A step between cells, e.g. [`Direction::offset`](crate::geometry::Direction::offset).
+pub type Step = Point2<i64>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Reads one cell per char with `f`, which returns `None` for a char that
+    /// isn't a valid cell. Every row must be as wide as the first.
+    pub fn parse(
+        input: &str,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+        for (r, l) in input.lines().enumerate() {
+            for (c, ch) in l.char_indices() {
+                let cell = f(ch).ok_or_else(|| {
+                    let found = &l[c..c + ch.len_utf8()];
+                    ParseError::new(l, found, expected).at_line(r + 1)
+                })?;
+                cells.push(cell);
+            }
+
+            let w = l.chars().count();
+            if *width.get_or_insert(w) != w {
+                let err = ParseError::new(l, l, "a row as wide as the first");
+                return Err(err.at_line(r + 1));
+            }
+            height += 1;
+        }
+
+        if cells.is_empty() {
+            return Err(ParseError::missing(input, expected));
+        }
+
+        Ok(Self {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.y * self.width + pos.x])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.y * self.width + pos.x])
+    }
+
+    /// One step from `pos`, if that is still on the grid.
+    pub fn step(&self, pos: Pos, step: Step) -> Option<Pos> {
+        let next = Pos::new(
+            pos.x.checked_add_signed(step.x as isize)?,
+            pos.y.checked_add_signed(step.y as isize)?,
+        );
+        self.contains(next).then_some(next)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Pos::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The first position, row by row, whose cell matches `pred`.
+    pub fn position(&self, pred: impl Fn(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, t)| pred(t)).map(|(p, _)| p)
+    }
+
+    /// The cells up, down, left and right of `pos` that are on the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Step::default()
+            .neighbours4()
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    /// As [`Grid::neighbours4`], diagonals included.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        Step::default()
+            .neighbours8()
+            .into_iter()
+            .filter_map(move |d| self.step(pos, d))
+    }
+
+    pub fn row(&self, y: usize) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        self.iter().skip(y * width).take(width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = (Pos, &T)> {
+        self.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    /// The positions walked from `from`, not including it, taking `step` each
+    /// time until falling off the grid.
+    pub fn ray(&self, from: Pos, step: Step) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.step(from, step), move |&p| self.step(p, step))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &Self::Output {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut Self::Output {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside the grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A board without edges, holding only the cells that have been set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coord, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reads a char map as [`Grid::parse`] does, except that `blank` chars are
+    /// left unset and the rows may be ragged.
+    pub fn parse(
+        input: &str,
+        blank: char,
+        expected: &str,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut grid = Self::new();
+        for (y, l) in input.lines().enumerate() {
+            for (x, (i, ch)) in l.char_indices().enumerate() {
+                if ch == blank {
+                    continue;
+                }
+
+                let cell = f(ch).ok_or_else(|| {
+                    let found = &l[i..i + ch.len_utf8()];
+                    ParseError::new(l, found, expected).at_line(y + 1)
+                })?;
+                grid.insert(Coord::new(x as i64, y as i64), cell);
+            }
+        }
+
+        Ok(grid)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Coord) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Coord) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Coord) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn insert(&mut self, pos: Coord, cell: T) -> Option<T> {
+        self.cells.insert(pos, cell)
+    }
+
+    pub fn remove(&mut self, pos: Coord) -> Option<T> {
+        self.cells.remove(&pos)
+    }
+
+    /// The set positions, in no particular order.
+    pub fn positions(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells.iter().map(|(p, t)| (*p, t))
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle
+    /// holding every set cell.
+    pub fn bounds(&self) -> Option<(Coord, Coord)> {
+        let xs = self.cells.keys().map(|p| p.x);
+        let ys = self.cells.keys().map(|p| p.y);
+        Some((
+            Coord::new(xs.clone().min()?, ys.clone().min()?),
+            Coord::new(xs.max()?, ys.max()?),
+        ))
+    }
+
+    pub fn neighbours4(&self, pos: Coord) -> impl Iterator<Item = Coord> {
+        pos.neighbours4().into_iter()
+    }
+
+    pub fn neighbours8(&self, pos: Coord) -> impl Iterator<Item = Coord> {
+        pos.neighbours8().into_iter()
+    }
+
+    /// The set cells of row `y`, left to right.
+    pub fn row(&self, y: i64) -> impl Iterator<Item = (Coord, &T)> {
+        let (min, max) = self.bounds().map_or((0, -1), |(min, max)| (min.x, max.x));
+        (min..=max).filter_map(move |x| {
+            let p = Coord::new(x, y);
+            self.get(p).map(|t| (p, t))
+        })
+    }
+
+    /// The set cells of column `x`, top to bottom.
+    pub fn column(&self, x: i64) -> impl Iterator<Item = (Coord, &T)> {
+        let (min, max) = self.bounds().map_or((0, -1), |(min, max)| (min.y, max.y));
+        (min..=max).filter_map(move |y| {
+            let p = Coord::new(x, y);
+            self.get(p).map(|t| (p, t))
+        })
+    }
+
+    /// The positions walked from `from`, not including it, taking `step` each
+    /// time until leaving the [`SparseGrid::bounds`].
+    pub fn ray(&self, from: Coord, step: Step) -> impl Iterator<Item = Coord> {
+        let bounds = self.bounds();
+        let inside = move |p: &Coord| {
+            bounds.is_some_and(|(min, max)| {
+                (min.x..=max.x).contains(&p.x) && (min.y..=max.y).contains(&p.y)
+            })
+        };
+        std::iter::successors(Some(from + step), move |p| Some(*p + step)).take_while(inside)
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Draws the bounding rectangle, with '.' for the unset cells.
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                match self.get(Coord::new(x, y)) {
+                    Some(cell) => write!(f, "{cell}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::Direction;
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::parse("123\n456", "a digit", |c| c.to_digit(10)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(2, 1)], 6);
+        assert_eq!(grid.get(Pos::new(0, 2)), None);
+        let neighbours: Vec<_> = grid.neighbours4(Pos::new(0, 0)).collect();
+        assert_eq!(neighbours, [Pos::new(0, 1), Pos::new(1, 0)]);
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 5);
+        let ray: Vec<_> = grid.ray(Pos::new(2, 1), Direction::Left.offset()).collect();
+        assert_eq!(ray, [Pos::new(1, 1), Pos::new(0, 1)]);
+        let column: Vec<_> = grid.column(1).map(|(_, d)| *d).collect();
+        assert_eq!(column, [2, 5]);
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let err = Grid::parse("12\n4x", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        let err = Grid::parse("12\n456", "a digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.expected, "a row as wide as the first");
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let grid = SparseGrid::parse(" #\n#.#", '.', "# or space", |c| match c {
+            '#' => Some('#'),
+            ' ' => Some('~'),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(grid.len(), 4);
+        assert_eq!(grid.bounds(), Some((Coord::new(0, 0), Coord::new(2, 1))));
+        let row: Vec<_> = grid.row(1).map(|(p, _)| p).collect();
+        assert_eq!(row, [Coord::new(0, 1), Coord::new(2, 1)]);
+        let ray: Vec<_> = grid.ray(Coord::new(0, 0), Coord::new(1, 1)).collect();
+        assert_eq!(ray, [Coord::new(1, 1)]);
+        assert_eq!(grid.to_string(), "~#.\n#.#\n");
+    }
+}
@@ -0,0 +1,288 @@
+//! Writing boards and animation frames out as image files: PPM and PNG
+//! rasters and SVG, each encoded here so no other tools are needed.
+//!
+//! The PNG encoder keeps things simple and stores its pixels uncompressed,
+//! which any viewer can read but makes for big files.
+use std::{fs, path::Path};
+
+use crate::{
+    grid::{Grid, SparseGrid},
+    visualize::Frame,
+    Result,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+
+    /// A colour from blue through green to red as `t` goes from 0 to 1, for
+    /// showing a height or a distance.
+    pub fn gradient(t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |v: f64| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+        Rgb(
+            channel(2.0 * t - 1.0),
+            channel(1.0 - (2.0 * t - 1.0).abs()),
+            channel(1.0 - 2.0 * t),
+        )
+    }
+}
+
+/// A grid of pixels.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![fill; width * height],
+        }
+    }
+
+    /// A pixel for each cell of `grid`.
+    pub fn from_grid<T>(grid: &Grid<T>, colour: impl Fn(&T) -> Rgb) -> Self {
+        let mut image = Self::new(grid.width(), grid.height(), Rgb::BLACK);
+        for (p, cell) in grid.iter() {
+            image.set(p.x, p.y, colour(cell));
+        }
+        image
+    }
+
+    /// A pixel for each cell in the bounding box of `grid`, `background`
+    /// where nothing is set.
+    pub fn from_sparse<T>(
+        grid: &SparseGrid<T>,
+        background: Rgb,
+        colour: impl Fn(&T) -> Rgb,
+    ) -> Self {
+        let Some((min, max)) = grid.bounds() else {
+            return Self::new(0, 0, background);
+        };
+
+        let width = (max.x - min.x + 1) as usize;
+        let height = (max.y - min.y + 1) as usize;
+        let mut image = Self::new(width, height, background);
+        for (p, cell) in grid.iter() {
+            image.set((p.x - min.x) as usize, (p.y - min.y) as usize, colour(cell));
+        }
+        image
+    }
+
+    /// A pixel for each cell of an animation frame, black where it is blank.
+    pub fn from_frame(frame: &Frame) -> Self {
+        let mut image = Self::new(frame.width(), frame.height(), Rgb::BLACK);
+        for y in 0..frame.height() {
+            for x in 0..frame.width() {
+                let (c, colour) = frame.cell(x, y);
+                if c != ' ' {
+                    image.set(x, y, colour.rgb());
+                }
+            }
+        }
+        image
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Colours a pixel; ones outside the image are ignored.
+    pub fn set(&mut self, x: usize, y: usize, colour: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = colour;
+        }
+    }
+
+    /// Each pixel blown up to a `factor` by `factor` square.
+    pub fn scale(&self, factor: usize) -> Self {
+        let mut image = Self::new(self.width * factor, self.height * factor, Rgb::BLACK);
+        for y in 0..image.height {
+            for x in 0..image.width {
+                image.pixels[y * image.width + x] =
+                    self.pixels[(y / factor) * self.width + x / factor];
+            }
+        }
+        image
+    }
+
+    /// Binary PPM (P6).
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut out = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        for Rgb(r, g, b) in &self.pixels {
+            out.extend([r, g, b]);
+        }
+        out
+    }
+
+    /// An 8 bit RGB PNG.
+    pub fn to_png(&self) -> Vec<u8> {
+        // each row starts with its filter type, 0 for none
+        let mut raw = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            raw.push(0);
+            for Rgb(r, g, b) in row {
+                raw.extend([r, g, b]);
+            }
+        }
+
+        let mut header = vec![];
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // bit depth 8, colour type 2 (RGB), default compression, filtering
+        // and no interlacing
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut out = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut out, b"IHDR", &header);
+        png_chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut out, b"IEND", &[]);
+        out
+    }
+
+    /// An SVG with a square per pixel, `size` units across, with runs of the
+    /// same colour along a row merged into one rectangle.
+    pub fn to_svg(&self, size: usize) -> String {
+        let mut out = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\n\
+             <rect width=\"{}\" height=\"{}\" fill=\"#000000\"/>\n",
+            self.width * size,
+            self.height * size,
+            self.width,
+            self.height,
+            self.width,
+            self.height,
+        );
+
+        for y in 0..self.height {
+            let row = &self.pixels[y * self.width..(y + 1) * self.width];
+            let mut x = 0;
+            while x < row.len() {
+                let colour = row[x];
+                let run = row[x..].iter().take_while(|c| **c == colour).count();
+                if colour != Rgb::BLACK {
+                    let Rgb(r, g, b) = colour;
+                    out += &format!(
+                        "<rect x=\"{x}\" y=\"{y}\" width=\"{run}\" height=\"1\" \
+                         fill=\"#{r:02x}{g:02x}{b:02x}\"/>\n"
+                    );
+                }
+                x += run;
+            }
+        }
+
+        out += "</svg>\n";
+        out
+    }
+
+    /// Writes the image in the format its extension names: `.ppm`, `.png` or
+    /// `.svg`. Rasters get `scale` pixels per cell, SVGs `scale` units.
+    pub fn save(&self, path: &Path, scale: usize) -> Result<()> {
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => self.scale(scale).to_ppm(),
+            Some("png") => self.scale(scale).to_png(),
+            Some("svg") => self.to_svg(scale).into_bytes(),
+            _ => {
+                let msg = format!("{}: expected a .ppm, .png or .svg file", path.display());
+                return Err(msg.into());
+            }
+        };
+
+        fs::write(path, bytes).map_err(|e| format!("{}: {e}", path.display()).into())
+    }
+}
+
+fn png_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend((data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend(kind);
+    out.extend(data);
+    let crc = crc32(&out[start..]);
+    out.extend(crc.to_be_bytes());
+}
+
+/// A zlib stream holding `data` in uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // deflate, 32K window, no dictionary, and a check that makes the two
+    // bytes a multiple of 31
+    let mut out = vec![0x78, 0x01];
+
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        out.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(last as u8);
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(block);
+    }
+
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image() {
+        let grid = Grid::parse("#.\n.#", "", |c| Some(c == '#')).unwrap();
+        let image = Image::from_grid(&grid, |lit| if *lit { Rgb(255, 0, 0) } else { Rgb::BLACK });
+        assert_eq!(image.get(1, 1), Some(Rgb(255, 0, 0)));
+
+        let ppm = image.to_ppm();
+        assert!(ppm.starts_with(b"P6\n2 2\n255\n"));
+        assert_eq!(ppm.len(), 11 + 2 * 2 * 3);
+
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+        let png = image.scale(3).to_png();
+        assert!(png.starts_with(b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR\0\0\0\x06\0\0\0\x06"));
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+
+        let svg = image.to_svg(10);
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"1\" height=\"1\" fill=\"#ff0000\"/>"));
+        assert_eq!(svg.matches("<rect").count(), 3);
+    }
+}
@@ -0,0 +1,160 @@
+use std::{
+    fmt,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, IsTerminal, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::Result;
+
+const USAGE: &str = "\
+options: --sample [name]  read the day's input/<name>.txt, input/sample.txt by default
+         --input <path>   read the puzzle input from a file
+         --visualize [ms] animate the simulation, on the days that have one
+         --export <path>  write the animation's frames to .png, .ppm or .svg files
+         --format <fmt>   print the answers as json, csv or text (the default)
+         -v, -vv          show the solution's diagnostics on stderr, -vv for more
+
+Without --sample or --input the puzzle input is read from stdin.";
+
+/// Where a day's puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    /// One of the day's own files, `input/<name>.txt`, such as `sample`,
+    /// `sample2` or `input`.
+    Named(String),
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Input {
+    /// Reads `--sample [name]` or `--input <path>` from a day binary's
+    /// arguments, falling back to stdin.
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Self> {
+        let mut input = Input::Stdin;
+        let mut args = args.into_iter().peekable();
+
+        while let Some(arg) = args.next() {
+            input = match arg.as_str() {
+                "--sample" | "-s" => {
+                    let name = args.next_if(|a| !a.starts_with('-'));
+                    Input::Named(name.unwrap_or_else(|| "sample".to_string()))
+                }
+                "--input" | "-i" => {
+                    let path = args.next().ok_or("--input needs a path")?;
+                    Input::Path(path.into())
+                }
+                _ => return Err(format!("unrecognized argument: {arg}\n\n{USAGE}").into()),
+            };
+        }
+
+        Ok(input)
+    }
+
+    /// The input's text. `day_dir` is the day's crate, which holds its named
+    /// inputs. Missing and empty inputs are errors, so a typo in a name
+    /// doesn't quietly solve an empty puzzle.
+    pub fn read(&self, day_dir: &Path) -> Result<String> {
+        let mut text = String::new();
+        self.reader(day_dir)?
+            .read_to_string(&mut text)
+            .map_err(|e| format!("{self}: {e}"))?;
+
+        if text.trim().is_empty() {
+            return Err(format!("{self} is empty").into());
+        }
+
+        Ok(text)
+    }
+
+    /// The input to be read a line at a time, for days that would rather not
+    /// hold all of it. As [`read`](Self::read), but an empty input is left for
+    /// the caller to notice.
+    pub fn reader(&self, day_dir: &Path) -> Result<Box<dyn BufRead>> {
+        let reader: Box<dyn BufRead> = match self {
+            Input::Named(name) => {
+                let path = day_dir.join("input").join(format!("{name}.txt"));
+                if !path.exists() {
+                    let known = named_inputs(day_dir);
+                    let known = match known.is_empty() {
+                        true => "there are none".to_string(),
+                        false => format!("the day has {}", known.join(", ")),
+                    };
+                    return Err(format!("{}: no such input, {known}", path.display()).into());
+                }
+                Box::new(open_file(&path)?)
+            }
+            Input::Path(path) => Box::new(open_file(path)?),
+            Input::Stdin => {
+                if io::stdin().is_terminal() {
+                    return Err(format!("no puzzle input on stdin\n\n{USAGE}").into());
+                }
+                Box::new(io::stdin().lock())
+            }
+        };
+
+        Ok(reader)
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::Named(name) => write!(f, "input/{name}.txt"),
+            Input::Path(path) => write!(f, "{}", path.display()),
+            Input::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+fn open_file(path: &Path) -> Result<BufReader<File>> {
+    File::open(path)
+        .map(BufReader::new)
+        .map_err(|e| format!("{}: {e}", path.display()).into())
+}
+
+/// The names of the `.txt` files in a day's `input` directory, sorted.
+fn named_inputs(day_dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(day_dir.join("input"))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "txt"))
+        .filter_map(|p| Some(p.file_stem()?.to_str()?.to_string()))
+        .collect();
+    names.sort();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Result<Input> {
+        Input::from_args(s.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_input() {
+        assert_eq!(args("").unwrap(), Input::Stdin);
+        assert_eq!(args("--sample").unwrap(), Input::Named("sample".into()));
+        assert_eq!(args("-s sample2").unwrap(), Input::Named("sample2".into()));
+        assert_eq!(
+            args("--input in.txt").unwrap(),
+            Input::Path("in.txt".into())
+        );
+        assert!(args("--input").is_err());
+        assert!(args("--part 2").is_err());
+
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let err = Input::Named("sample".into()).read(dir).unwrap_err();
+        assert!(err.to_string().contains("no such input"));
+        assert!(Input::Named("sample".into()).reader(dir).is_err());
+
+        let empty = std::env::temp_dir().join("aoc-common-empty-input.txt");
+        fs::write(&empty, "\n").unwrap();
+        let err = Input::Path(empty).read(dir).unwrap_err();
+        assert!(err.to_string().ends_with("is empty"));
+    }
+}
@@ -0,0 +1,25 @@
+//! Shared plumbing for the Advent of Code 2022 solutions.
+//!
+//! Every day crate depends on this library for its error type, input loading
+//! and the handful of helpers that used to be copied between days.
+
+pub mod cycle;
+pub mod diag;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+mod input;
+pub mod math;
+mod parse;
+pub mod pool;
+pub mod report;
+pub mod search;
+mod solution;
+pub mod testing;
+pub mod visualize;
+
+pub use input::Input;
+pub use parse::{parse_field, parse_lines, ParseError};
+pub use solution::{run, run_visual, run_with, Answer, Args, Flags, Solution};
+
+pub type Result<T> = ::std::result::Result<T, Box<dyn ::std::error::Error>>;
@@ -0,0 +1,126 @@
+use std::{error::Error, fmt, str::FromStr};
+
+/// Malformed puzzle input: where it went wrong, the text found there and what
+/// was expected instead. Lines and columns count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error at `found` in `source`, the text being parsed. When `found` is
+    /// a slice of `source` the line and column point at it, otherwise they
+    /// point at the start of `source`.
+    pub fn new(source: &str, found: &str, expected: impl Into<String>) -> Self {
+        let offset = (found.as_ptr() as usize)
+            .checked_sub(source.as_ptr() as usize)
+            .filter(|o| o + found.len() <= source.len())
+            .unwrap_or(0);
+        let before = source.get(..offset).unwrap_or_default();
+
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before
+                .rsplit('\n')
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .count()
+                + 1,
+            text: found.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Something expected was missing from the end of `source`.
+    pub fn missing(source: &str, expected: impl Into<String>) -> Self {
+        Self::new(source, &source[source.len()..], expected)
+    }
+
+    /// Shifts an error found while parsing `part` out to `source`, which
+    /// `part` is a slice of.
+    pub fn within(mut self, source: &str, part: &str) -> Self {
+        let start = Self::new(source, part, "");
+        if self.line == 1 {
+            self.column += start.column - 1;
+        }
+        self.line += start.line - 1;
+        self
+    }
+
+    /// Shifts the error from `source` into the whole input, where `source`
+    /// starts on `line`.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line += line - 1;
+        self
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found {:?}", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `found`, a slice of `source`, or reports what was expected there.
+pub fn parse_field<T: FromStr>(source: &str, found: &str, expected: &str) -> Result<T, ParseError> {
+    found
+        .parse()
+        .map_err(|_| ParseError::new(source, found, expected))
+}
+
+/// Parses every line of `input`, numbering any error with its line.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| l.parse().map_err(|e: ParseError| e.at_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_location() {
+        let source = "move 1 from 2 to 3\nmove x from 1 to 2";
+        let err = parse_field::<u32>(source, &source[24..25], "a number").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 6));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 6: expected a number, found \"x\""
+        );
+
+        let err = parse_lines::<Number>("1\n2\nthree").unwrap_err();
+        assert_eq!((err.line, err.column), (3, 1));
+    }
+
+    #[derive(Debug)]
+    struct Number;
+
+    impl FromStr for Number {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            parse_field::<u32>(s, s, "a number").map(|_| Number)
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! Spreading independent jobs over a few worker threads, for `aoc run all`
+//! and for days whose work splits into units that don't share state, like
+//! day 19's blueprints.
+//!
+//! Results always come back in the order the jobs went in, however the
+//! workers happened to finish them.
+//!
+//! A pool's workers split its thread budget between them, so a job that runs
+//! a pool of its own, like day 19 under `aoc run all`, only uses its share
+//! rather than starting a thread per CPU in every worker.
+//!
+//! ```
+//! use aoc_common::pool;
+//!
+//! let squares = pool::map(1..=5, pool::workers(), |n| n * n);
+//! assert_eq!(squares, [1, 4, 9, 16, 25]);
+//! ```
+use std::{
+    any::Any,
+    cell::Cell,
+    panic::{self, AssertUnwindSafe},
+    sync::Mutex,
+    thread,
+};
+
+thread_local! {
+    /// The budget of a pool's worker thread, its share of the pool's.
+    static SHARE: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// How many workers this thread should use: one per CPU, or just the one when
+/// that can't be found out, unless it is itself a pool's worker, which gets
+/// an even share of the pool's budget.
+pub fn workers() -> usize {
+    SHARE
+        .get()
+        .unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get()))
+}
+
+/// Runs `f` on every item on up to `workers` threads. A panic in `f` is passed
+/// on once the other jobs are done.
+pub fn map<T, R>(
+    items: impl IntoIterator<Item = T>,
+    workers: usize,
+    f: impl Fn(T) -> R + Sync,
+) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+{
+    map_catching(items, workers, f)
+        .into_iter()
+        .map(|r| r.unwrap_or_else(|msg| panic!("{msg}")))
+        .collect()
+}
+
+/// As [`map`], but a job that panics gives an `Err` with the panic's message
+/// and the rest carry on.
+pub fn map_catching<T, R>(
+    items: impl IntoIterator<Item = T>,
+    workers: usize,
+    f: impl Fn(T) -> R + Sync,
+) -> Vec<Result<R, String>>
+where
+    T: Send,
+    R: Send,
+{
+    let queue: Vec<(usize, T)> = items.into_iter().enumerate().collect();
+    let len = queue.len();
+    let queue = Mutex::new(queue.into_iter());
+    let results = Mutex::new((0..len).map(|_| None).collect::<Vec<_>>());
+
+    let run = || loop {
+        // take the lock only long enough to pop the next job
+        let next = queue.lock().unwrap().next();
+        let Some((idx, item)) = next else {
+            break;
+        };
+        let result = panic::catch_unwind(AssertUnwindSafe(|| f(item))).map_err(message);
+        results.lock().unwrap()[idx] = Some(result);
+    };
+
+    let workers = workers.clamp(1, len.max(1));
+    if workers == 1 {
+        run();
+    } else {
+        let share = (self::workers() / workers).max(1);
+        thread::scope(|scope| {
+            for _ in 0..workers {
+                scope.spawn(|| {
+                    SHARE.set(Some(share));
+                    run()
+                });
+            }
+        });
+    }
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|r| r.expect("every job has run"))
+        .collect()
+}
+
+/// What a panic was raised with, when it was a string.
+pub(crate) fn message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(msg) => *msg,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(msg) => msg.to_string(),
+            Err(_) => "panicked".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_catching() {
+        let results = map_catching(0..20, 4, |n| {
+            if n == 7 {
+                panic!("unlucky {n}");
+            }
+            n * 2
+        });
+
+        assert_eq!(results.len(), 20);
+        assert_eq!(results[3], Ok(6));
+        assert_eq!(results[7], Err("unlucky 7".to_string()));
+        assert_eq!(results[19], Ok(38));
+    }
+
+    #[test]
+    fn test_workers_share() {
+        let budget = workers();
+        let shares = map(0..2, 2, |_| (workers(), map(0..4, 4, |_| workers())));
+        let share = (budget / 2).max(1);
+        let inner = (share / 4).max(1);
+        assert_eq!(shares, [(share, vec![inner; 4]), (share, vec![inner; 4])]);
+
+        // running on the calling thread keeps its budget
+        assert_eq!(map(0..3, 1, |_| workers()), [budget; 3]);
+    }
+}
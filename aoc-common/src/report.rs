@@ -0,0 +1,259 @@
+//! Answers in a form scripts can read: a record per day and part, written as
+//! JSON or CSV when `--format` asks for it instead of the usual text. A day
+//! whose own flags ask for something other than its answers gives a [`Table`]
+//! instead, written the same ways.
+use std::{fmt, str::FromStr, time::Duration};
+
+use crate::Result;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Whatever the tool prints for people to read.
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format: {s}, expected json, csv or text")),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+            Format::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/// One part's answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    /// The answer, or what went wrong when `ok` is false.
+    pub answer: String,
+    pub ok: bool,
+    pub elapsed: Duration,
+    /// [`input_hash`] of the input the answer is for.
+    pub input_hash: u64,
+}
+
+/// A 64 bit FNV-1a hash, enough to tell which input an answer came from.
+pub fn input_hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, b| {
+        (hash ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// The day number from a day crate's directory, `aocNN`.
+pub fn day_number(day_dir: &std::path::Path) -> Result<u8> {
+    let name = day_dir.file_name().unwrap_or_default().to_string_lossy();
+    name.strip_prefix("aoc")
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| format!("{name} isn't a day crate").into())
+}
+
+pub fn to_json(records: &[Record]) -> String {
+    let records: Vec<String> = records
+        .iter()
+        .map(|r| {
+            format!(
+                "  {{\"day\": {}, \"part\": {}, \"answer\": \"{}\", \"ok\": {}, \"elapsed_ns\": {}, \"input_hash\": \"{:016x}\"}}",
+                r.day,
+                r.part,
+                json_escape(&r.answer),
+                r.ok,
+                r.elapsed.as_nanos(),
+                r.input_hash
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]\n", records.join(",\n"))
+}
+
+pub fn to_csv(records: &[Record]) -> String {
+    let mut out = "day,part,answer,ok,elapsed_ns,input_hash\n".to_string();
+    for r in records {
+        out += &format!(
+            "{},{},{},{},{},{:016x}\n",
+            r.day,
+            r.part,
+            csv_field(&r.answer),
+            r.ok,
+            r.elapsed.as_nanos(),
+            r.input_hash
+        );
+    }
+    out
+}
+
+/// A report a day prints in place of its answers, such as day 2's analysis:
+/// named columns and a row of values under them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Table {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(columns: &[&str]) -> Self {
+        Self {
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            rows: vec![],
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<String>) {
+        assert_eq!(row.len(), self.columns.len(), "a value for every column");
+        self.rows.push(row);
+    }
+
+    /// The columns lined up under their names.
+    pub fn to_text(&self) -> String {
+        let widths: Vec<usize> = (0..self.columns.len())
+            .map(|i| {
+                let values = self.rows.iter().map(|r| r[i].chars().count());
+                values.chain([self.columns[i].len()]).max().unwrap_or(0)
+            })
+            .collect();
+
+        let mut out = String::new();
+        for row in [&self.columns].into_iter().chain(&self.rows) {
+            let cells: Vec<String> = row
+                .iter()
+                .zip(&widths)
+                .map(|(v, &w)| format!("{v:w$}"))
+                .collect();
+            out += cells.join("  ").trim_end();
+            out.push('\n');
+        }
+        out
+    }
+
+    /// An object per row, led by the day it's for. Every value is a string.
+    pub fn to_json(&self, day: u8) -> String {
+        let rows: Vec<String> = self
+            .rows
+            .iter()
+            .map(|row| {
+                let fields: Vec<String> = self
+                    .columns
+                    .iter()
+                    .zip(row)
+                    .map(|(c, v)| format!(", \"{}\": \"{}\"", json_escape(c), json_escape(v)))
+                    .collect();
+                format!("  {{\"day\": {day}{}}}", fields.concat())
+            })
+            .collect();
+
+        format!("[\n{}\n]\n", rows.join(",\n"))
+    }
+
+    pub fn to_csv(&self, day: u8) -> String {
+        let header: Vec<String> = self.columns.iter().map(|c| csv_field(c)).collect();
+        let mut out = format!("day,{}\n", header.join(","));
+        for row in &self.rows {
+            let fields: Vec<String> = row.iter().map(|v| csv_field(v)).collect();
+            out += &format!("{day},{}\n", fields.join(","));
+        }
+        out
+    }
+}
+
+fn json_escape(s: &str) -> String {
+    let mut out = String::new();
+    for c in s.chars() {
+        match c {
+            '"' => out += "\\\"",
+            '\\' => out += "\\\\",
+            '\n' => out += "\\n",
+            '\t' => out += "\\t",
+            c if (c as u32) < 0x20 => out += &format!("\\u{:04x}", c as u32),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// Quoted, with quotes doubled, when it holds anything that would break the
+/// row up.
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report() {
+        let records = [
+            Record {
+                day: 10,
+                part: 2,
+                answer: "##..\n#..#".to_string(),
+                ok: true,
+                elapsed: Duration::from_micros(3),
+                input_hash: input_hash(""),
+            },
+            Record {
+                day: 22,
+                part: 2,
+                answer: "panicked: \"oops\", again".to_string(),
+                ok: false,
+                elapsed: Duration::ZERO,
+                input_hash: 1,
+            },
+        ];
+
+        assert_eq!(
+            to_json(&records),
+            "[\n  {\"day\": 10, \"part\": 2, \"answer\": \"##..\\n#..#\", \"ok\": true, \
+             \"elapsed_ns\": 3000, \"input_hash\": \"cbf29ce484222325\"},\n  \
+             {\"day\": 22, \"part\": 2, \"answer\": \"panicked: \\\"oops\\\", again\", \"ok\": false, \
+             \"elapsed_ns\": 0, \"input_hash\": \"0000000000000001\"}\n]\n"
+        );
+        assert_eq!(
+            to_csv(&records),
+            "day,part,answer,ok,elapsed_ns,input_hash\n\
+             10,2,\"##..\n#..#\",true,3000,cbf29ce484222325\n\
+             22,2,\"panicked: \"\"oops\"\", again\",false,0,0000000000000001\n"
+        );
+        assert_eq!("csv".parse(), Ok(Format::Csv));
+
+        let mut table = Table::new(&["mapping", "total"]);
+        table.push(vec!["X=Rock".to_string(), "15".to_string()]);
+        table.push(vec!["X=Lose, Y=Win".to_string(), "9".to_string()]);
+        assert_eq!(
+            table.to_text(),
+            "mapping        total\nX=Rock         15\nX=Lose, Y=Win  9\n"
+        );
+        assert_eq!(
+            table.to_json(2),
+            "[\n  {\"day\": 2, \"mapping\": \"X=Rock\", \"total\": \"15\"},\n  \
+             {\"day\": 2, \"mapping\": \"X=Lose, Y=Win\", \"total\": \"9\"}\n]\n"
+        );
+        assert_eq!(
+            table.to_csv(2),
+            "day,mapping,total\n2,X=Rock,15\n2,\"X=Lose, Y=Win\",9\n"
+        );
+    }
+}
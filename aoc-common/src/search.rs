@@ -0,0 +1,261 @@
+//! Shortest path searches over states reached through a `successors` closure.
+//!
+//! A [`Search`] starts from one or more states and can run as a breadth first
+//! search, Dijkstra, A* or a plain flood fill. Breadth first searches take
+//! successors as bare states, one step each; Dijkstra and A* take them as
+//! `(state, cost)` pairs.
+//!
+//! ```
+//! use aoc_common::search::Search;
+//!
+//! // the shortest way to count from 1 to 10 by adding one or doubling
+//! let found = Search::from(1)
+//!     .with_path()
+//!     .bfs(|n| [n + 1, n * 2], |n| *n == 10)
+//!     .unwrap();
+//! assert_eq!(found.cost, 4);
+//! assert_eq!(found.path, Some(vec![1, 2, 4, 5, 10]));
+//! ```
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// The goal a search reached and how it got there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Found<S, C> {
+    pub goal: S,
+    pub cost: C,
+    /// From the start to the goal, both included, when asked for with
+    /// [`Search::with_path`].
+    pub path: Option<Vec<S>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Search<S> {
+    starts: Vec<S>,
+    track_path: bool,
+}
+
+impl<S: Clone + Eq + Hash> Search<S> {
+    pub fn from(start: S) -> Self {
+        Self::from_all([start])
+    }
+
+    /// A search from every one of `starts` at once, so the goal found is the
+    /// nearest to any of them.
+    pub fn from_all(starts: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            starts: starts.into_iter().collect(),
+            track_path: false,
+        }
+    }
+
+    /// Remembers each state's predecessor so the path to the goal can be
+    /// returned.
+    pub fn with_path(mut self) -> Self {
+        self.track_path = true;
+        self
+    }
+
+    /// The fewest steps to a state matching `goal`.
+    pub fn bfs<I>(
+        self,
+        mut successors: impl FnMut(&S) -> I,
+        mut goal: impl FnMut(&S) -> bool,
+    ) -> Option<Found<S, usize>>
+    where
+        I: IntoIterator<Item = S>,
+    {
+        let mut trail = Trail::new(self.track_path);
+        let mut seen: HashMap<S, usize> = HashMap::new();
+        let mut queue = VecDeque::new();
+        for s in self.starts {
+            if seen.insert(s.clone(), 0).is_none() {
+                queue.push_back((s, 0));
+            }
+        }
+
+        while let Some((state, steps)) = queue.pop_front() {
+            if goal(&state) {
+                return Some(trail.found(state, steps));
+            }
+
+            for next in successors(&state) {
+                if let Entry::Vacant(e) = seen.entry(next.clone()) {
+                    e.insert(steps + 1);
+                    trail.record(&next, &state);
+                    queue.push_back((next, steps + 1));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Every state reachable from the starts, with the fewest steps to it.
+    pub fn flood_fill<I>(self, mut successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+    where
+        I: IntoIterator<Item = S>,
+    {
+        let mut seen: HashMap<S, usize> = HashMap::new();
+        let mut queue = VecDeque::new();
+        for s in self.starts {
+            if seen.insert(s.clone(), 0).is_none() {
+                queue.push_back((s, 0));
+            }
+        }
+
+        while let Some((state, steps)) = queue.pop_front() {
+            for next in successors(&state) {
+                if let Entry::Vacant(e) = seen.entry(next.clone()) {
+                    e.insert(steps + 1);
+                    queue.push_back((next, steps + 1));
+                }
+            }
+        }
+
+        seen
+    }
+
+    /// The cheapest way to a state matching `goal`.
+    pub fn dijkstra<C, I>(
+        self,
+        successors: impl FnMut(&S) -> I,
+        goal: impl FnMut(&S) -> bool,
+    ) -> Option<Found<S, C>>
+    where
+        C: Copy + Ord + Default + Add<Output = C>,
+        I: IntoIterator<Item = (S, C)>,
+    {
+        self.astar(successors, |_| C::default(), goal)
+    }
+
+    /// As [`Search::dijkstra`], guided by `heuristic`, which must never
+    /// overestimate the cost still to go.
+    pub fn astar<C, I>(
+        self,
+        mut successors: impl FnMut(&S) -> I,
+        mut heuristic: impl FnMut(&S) -> C,
+        mut goal: impl FnMut(&S) -> bool,
+    ) -> Option<Found<S, C>>
+    where
+        C: Copy + Ord + Default + Add<Output = C>,
+        I: IntoIterator<Item = (S, C)>,
+    {
+        let mut trail = Trail::new(self.track_path);
+        let mut best: HashMap<S, C> = HashMap::new();
+        // states live in `open` and the heap orders their indexes, since S
+        // needn't be Ord
+        let mut open: Vec<(S, C)> = vec![];
+        let mut heap = BinaryHeap::new();
+        for s in self.starts {
+            best.insert(s.clone(), C::default());
+            heap.push(Reverse((heuristic(&s), open.len())));
+            open.push((s, C::default()));
+        }
+
+        while let Some(Reverse((_, idx))) = heap.pop() {
+            let (state, cost) = open[idx].clone();
+            if best.get(&state).is_some_and(|b| *b < cost) {
+                continue;
+            }
+
+            if goal(&state) {
+                return Some(trail.found(state, cost));
+            }
+
+            for (next, step) in successors(&state) {
+                let next_cost = cost + step;
+                if best.get(&next).is_some_and(|b| *b <= next_cost) {
+                    continue;
+                }
+
+                best.insert(next.clone(), next_cost);
+                trail.record(&next, &state);
+                heap.push(Reverse((next_cost + heuristic(&next), open.len())));
+                open.push((next, next_cost));
+            }
+        }
+
+        None
+    }
+}
+
+/// Predecessors, when the path is wanted.
+struct Trail<S> {
+    prev: Option<HashMap<S, S>>,
+}
+
+impl<S: Clone + Eq + Hash> Trail<S> {
+    fn new(track: bool) -> Self {
+        Self {
+            prev: track.then(HashMap::new),
+        }
+    }
+
+    fn record(&mut self, next: &S, from: &S) {
+        if let Some(prev) = &mut self.prev {
+            prev.insert(next.clone(), from.clone());
+        }
+    }
+
+    fn found<C>(self, goal: S, cost: C) -> Found<S, C> {
+        let path = self.prev.map(|prev| {
+            let mut path = vec![goal.clone()];
+            while let Some(p) = prev.get(path.last().unwrap()) {
+                path.push(p.clone());
+            }
+            path.reverse();
+            path
+        });
+
+        Found { goal, cost, path }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a..e with a costly direct edge from a to e
+    fn edges(s: &char) -> Vec<(char, u32)> {
+        match s {
+            'a' => vec![('b', 1), ('e', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 2)],
+            'd' => vec![('e', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_search() {
+        let bfs = Search::from('a')
+            .bfs(|s| edges(s).into_iter().map(|(n, _)| n), |s| *s == 'e')
+            .unwrap();
+        assert_eq!((bfs.cost, bfs.path), (1, None));
+
+        let cheapest = Search::from('a')
+            .with_path()
+            .dijkstra(edges, |s| *s == 'e')
+            .unwrap();
+        assert_eq!(cheapest.cost, 6);
+        assert_eq!(cheapest.path, Some("abcde".chars().collect()));
+
+        let guided = Search::from('a').astar(edges, |s| 'e' as u32 - *s as u32, |s| *s == 'e');
+        assert_eq!(guided.map(|f| f.cost), Some(6));
+
+        let nearest = Search::from_all(['a', 'c'])
+            .dijkstra(edges, |s| *s == 'e')
+            .unwrap();
+        assert_eq!(nearest.cost, 3);
+
+        let reached = Search::from('b').flood_fill(|s| edges(s).into_iter().map(|(n, _)| n));
+        assert_eq!(reached.len(), 4);
+        assert_eq!(reached[&'e'], 3);
+        assert!(Search::from('e').bfs(|_| [], |s| *s == 'a').is_none());
+    }
+}
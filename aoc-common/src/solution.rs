@@ -0,0 +1,271 @@
+use std::{
+    env, fmt,
+    iter::Peekable,
+    marker::PhantomData,
+    path::Path,
+    process,
+    time::{Duration, Instant},
+    vec,
+};
+
+use crate::{
+    diag,
+    report::{self, Format, Record, Table},
+    visualize::{Screen, Visualize, DEFAULT_DELAY},
+    Input, Result,
+};
+
+/// A puzzle answer, in the form the site expects it to be typed in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+    /// The part has no answer, e.g. day 25 only has a single puzzle.
+    Unsolved,
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+            Answer::Unsolved => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    i64::try_from(n)
+                        .map(Answer::Number)
+                        .unwrap_or_else(|_| Answer::Text(n.to_string()))
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// A single day's puzzle: parse the input once, then answer both parts from
+/// the parsed form.
+pub trait Solution {
+    fn parse(input: &str) -> Result<Self>
+    where
+        Self: Sized;
+
+    fn part1(&self) -> Answer;
+
+    fn part2(&self) -> Answer;
+}
+
+/// The arguments still to come, for [`Flags::take`] to find a flag's value in.
+pub type Args = Peekable<vec::IntoIter<String>>;
+
+/// Flags a day binary takes on top of the ones every day has, for
+/// [`run_with`]. They can change how the input is parsed, or ask for a report
+/// in place of the answers.
+pub trait Flags: Default {
+    type Solution: Solution;
+
+    /// Takes `arg` if it is one of the day's flags, and any value it needs
+    /// from `args`. Anything else gives `false` and goes on to the usual flags.
+    fn take(&mut self, arg: &str, args: &mut Args) -> Result<bool>;
+
+    /// Parses the input as the flags say.
+    fn parse(&self, input: &str) -> Result<Self::Solution> {
+        Self::Solution::parse(input)
+    }
+
+    /// What to print instead of the answers, if the flags asked for
+    /// something else.
+    fn report(&self, _solution: &Self::Solution) -> Option<Result<Table>> {
+        None
+    }
+
+    /// As [`report`](Self::report), for a report made as the input is read
+    /// (see [`Input::reader`]) rather than from all of it parsed.
+    fn stream(&self, _input: &Input, _day_dir: &Path) -> Option<Result<Table>> {
+        None
+    }
+}
+
+/// The flags of a day that has none of its own.
+struct NoFlags<S>(PhantomData<S>);
+
+impl<S> Default for NoFlags<S> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<S: Solution> Flags for NoFlags<S> {
+    type Solution = S;
+
+    fn take(&mut self, _arg: &str, _args: &mut Args) -> Result<bool> {
+        Ok(false)
+    }
+}
+
+/// Entry point shared by the day binaries: reads the input picked by the
+/// command line (see [`Input::from_args`]), solves both parts and prints the
+/// answers, as text or in the `--format` asked for. `day_dir` is the day's
+/// crate directory, where its named inputs live. `-v` and `-vv` turn on the
+/// solution's diagnostics, on stderr. Bad arguments, missing input and
+/// malformed input are reported on stderr with a nonzero exit code.
+pub fn run<S: Solution>(day_dir: &str) {
+    finish(solve::<NoFlags<S>>(Path::new(day_dir), None));
+}
+
+/// As [`run`], for a day with [`Flags`] of its own.
+pub fn run_with<F: Flags>(day_dir: &str) {
+    finish(solve::<F>(Path::new(day_dir), None));
+}
+
+/// As [`run`], for a day that can also animate its simulation with
+/// `--visualize [ms]`, waiting `ms` milliseconds between frames, or export the
+/// frames as images with `--export <path>`, just frame `n` with `--frame <n>`.
+pub fn run_visual<S: Solution + Visualize>(day_dir: &str) {
+    finish(solve::<NoFlags<S>>(Path::new(day_dir), Some(S::visualize)));
+}
+
+fn finish(result: Result<()>) {
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
+
+fn solve<F: Flags>(day_dir: &Path, visualize: Option<fn(&F::Solution, &mut Screen)>) -> Result<()> {
+    let mut args: Args = env::args()
+        .skip(1)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .peekable();
+    let mut flags = F::default();
+    let mut rest = vec![];
+    let mut screen = None;
+    let (mut export, mut frame) = (None, None);
+    let mut format = Format::Text;
+    while let Some(arg) = args.next() {
+        if flags.take(&arg, &mut args)? {
+            continue;
+        }
+        match arg.as_str() {
+            "--format" | "-f" => {
+                format = args.next().ok_or("--format needs a value")?.parse()?;
+            }
+            "--verbose" | "-v" => diag::set_verbosity(diag::verbosity() + 1),
+            "-vv" => diag::set_verbosity(2),
+            "--visualize" => {
+                let delay = match args.next_if(|a| !a.starts_with('-')) {
+                    Some(ms) => Duration::from_millis(
+                        ms.parse()
+                            .map_err(|_| format!("invalid frame delay: {ms}"))?,
+                    ),
+                    None => DEFAULT_DELAY,
+                };
+                screen = Some(Screen::new(delay));
+            }
+            "--export" => export = Some(args.next().ok_or("--export needs a path")?),
+            "--frame" => {
+                let n = args.next().ok_or("--frame needs a frame number")?;
+                let n = n
+                    .parse()
+                    .map_err(|_| format!("invalid frame number: {n}"))?;
+                frame = Some(n);
+            }
+            _ => rest.push(arg),
+        }
+    }
+
+    if let Some(path) = export {
+        let export = Screen::export(path);
+        screen = Some(match frame {
+            Some(n) => export.only_frame(n),
+            None => export,
+        });
+    } else if frame.is_some() {
+        return Err("--frame goes with --export".into());
+    }
+    if screen.is_some() && visualize.is_none() {
+        return Err("this day has no visualisation".into());
+    }
+
+    let day = report::day_number(day_dir)?;
+    let input = Input::from_args(rest)?;
+    if let Some(table) = flags.stream(&input, day_dir) {
+        return print_table(&table?, day, format);
+    }
+
+    let input = input.read(day_dir)?;
+    let start = Instant::now();
+    let solution = flags.parse(&input)?;
+    let parsed = start.elapsed();
+
+    if let Some((visualize, mut screen)) = visualize.zip(screen) {
+        visualize(&solution, &mut screen);
+        screen.finish()?;
+    }
+
+    if let Some(table) = flags.report(&solution) {
+        return print_table(&table?, day, format);
+    }
+
+    let input_hash = report::input_hash(&input);
+    let records: Vec<Record> = [1, 2]
+        .into_iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => solution.part1(),
+                _ => solution.part2(),
+            };
+            // timed as if the part were solved on its own, parsing included
+            Record {
+                day,
+                part,
+                answer: answer.to_string(),
+                ok: true,
+                elapsed: parsed + start.elapsed(),
+                input_hash,
+            }
+        })
+        .collect();
+
+    match format {
+        Format::Text => {
+            for r in &records {
+                println!("Part {}: {}", r.part, r.answer);
+            }
+        }
+        Format::Json => print!("{}", report::to_json(&records)),
+        Format::Csv => print!("{}", report::to_csv(&records)),
+    }
+
+    Ok(())
+}
+
+fn print_table(table: &Table, day: u8, format: Format) -> Result<()> {
+    match format {
+        Format::Text => print!("{}", table.to_text()),
+        Format::Json => print!("{}", table.to_json(day)),
+        Format::Csv => print!("{}", table.to_csv(day)),
+    }
+    Ok(())
+}
@@ -0,0 +1,191 @@
+//! Randomised tests without pulling in a framework: [`check`] tries a property
+//! on generated values and [`fuzz`] throws mangled input at a parser to make
+//! sure it turns garbage into an error rather than a panic.
+//!
+//! Every run uses the same seeds, so a failure always comes back, and the
+//! panic names the value or input that caused it.
+//!
+//! ```
+//! use aoc_common::testing::check;
+//!
+//! check(100, |rng| rng.range(-1000..=1000), |n| {
+//!     assert_eq!(n.to_string().parse::<i64>().unwrap(), *n);
+//! });
+//! ```
+use std::{
+    fmt::Debug,
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::pool::message;
+
+/// A small, fast, seedable generator (xorshift64*). Not for anything that
+/// needs real randomness.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // spread small seeds out, and keep clear of the all-zero state
+        Self(seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) | 1)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 >> 12;
+        self.0 ^= self.0 << 25;
+        self.0 ^= self.0 >> 27;
+        self.0.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    /// A number from 0 up to but not including `n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "below(0)");
+        (self.next_u64() % n as u64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let span = range.end().wrapping_sub(*range.start()) as u64;
+        let offset = match span.checked_add(1) {
+            Some(n) => self.next_u64() % n,
+            None => self.next_u64(),
+        };
+        range.start().wrapping_add(offset as i64)
+    }
+
+    /// True one time in `n`.
+    pub fn one_in(&mut self, n: usize) -> bool {
+        self.below(n) == 0
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    /// A string of characters from `alphabet`, its length in `len`.
+    pub fn string(&mut self, alphabet: &str, len: RangeInclusive<usize>) -> String {
+        let chars: Vec<char> = alphabet.chars().collect();
+        let len = self.range(*len.start() as i64..=*len.end() as i64) as usize;
+        (0..len).map(|_| *self.pick(&chars)).collect()
+    }
+}
+
+/// Runs `property` on `cases` values from `generate`.
+pub fn check<T: Debug>(
+    cases: usize,
+    mut generate: impl FnMut(&mut Rng) -> T,
+    property: impl Fn(&T),
+) {
+    for case in 0..cases {
+        let value = generate(&mut Rng::new(case as u64));
+        if let Err(e) = panic::catch_unwind(AssertUnwindSafe(|| property(&value))) {
+            panic!("case {case} failed for {value:?}: {}", message(e));
+        }
+    }
+}
+
+/// Bytes a mutation likes to drop in: digits, signs, brackets and the other
+/// punctuation puzzle inputs are made of.
+const INTERESTING: &[u8] = b"0123456789-+=,.:;[]()<>#$/ \n\tabcxyzXYZ";
+
+/// Calls `target` on `cases` inputs, each one of `seeds` (typically the
+/// sample) mangled a few times over, or now and then plain noise. Fails with
+/// the input that made `target` panic.
+pub fn fuzz(cases: usize, seeds: &[&str], target: impl Fn(&str)) {
+    for case in 0..cases {
+        let mut rng = Rng::new(case as u64);
+        let input = if seeds.is_empty() || rng.one_in(10) {
+            noise(&mut rng)
+        } else {
+            let mut bytes = rng.pick(seeds).as_bytes().to_vec();
+            for _ in 0..=rng.below(4) {
+                mutate(&mut rng, &mut bytes, seeds);
+            }
+            String::from_utf8_lossy(&bytes).into_owned()
+        };
+
+        if let Err(e) = panic::catch_unwind(AssertUnwindSafe(|| target(&input))) {
+            panic!("case {case} panicked on {input:?}: {}", message(e));
+        }
+    }
+}
+
+fn noise(rng: &mut Rng) -> String {
+    let bytes: Vec<u8> = (0..rng.below(64))
+        .map(|_| match rng.one_in(2) {
+            true => *rng.pick(INTERESTING),
+            false => rng.next_u64() as u8,
+        })
+        .collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn mutate(rng: &mut Rng, bytes: &mut Vec<u8>, seeds: &[&str]) {
+    let at = |rng: &mut Rng, bytes: &Vec<u8>| rng.below(bytes.len() + 1);
+    match rng.below(7) {
+        // replace a byte
+        0 if !bytes.is_empty() => {
+            let i = rng.below(bytes.len());
+            bytes[i] = *rng.pick(INTERESTING);
+        }
+        // insert one
+        1 => {
+            let i = at(rng, bytes);
+            bytes.insert(i, *rng.pick(INTERESTING));
+        }
+        // cut out a stretch
+        2 => {
+            let i = at(rng, bytes);
+            let end = (i + rng.below(16) + 1).min(bytes.len());
+            bytes.drain(i..end);
+        }
+        // repeat a stretch
+        3 => {
+            let i = at(rng, bytes);
+            let end = (i + rng.below(16) + 1).min(bytes.len());
+            let copy = bytes[i..end].to_vec();
+            bytes.splice(i..i, copy);
+        }
+        // stop early
+        4 => {
+            let i = at(rng, bytes);
+            bytes.truncate(i);
+        }
+        // a number too big for anything
+        5 => {
+            let i = at(rng, bytes);
+            bytes.splice(i..i, *b"99999999999999999999");
+        }
+        // the tail of another seed
+        _ => {
+            let other = rng.pick(seeds).as_bytes();
+            let i = at(rng, bytes);
+            let j = rng.below(other.len() + 1);
+            bytes.truncate(i);
+            bytes.extend(&other[j..]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3..=3)));
+        }
+        assert!((0..1000).any(|_| rng.range(-3..=3) == 3));
+
+        let failed =
+            panic::catch_unwind(|| check(100, |rng| rng.range(0..=9), |n| assert!(*n < 9)));
+        let msg = message(failed.unwrap_err());
+        assert!(msg.contains("failed for 9"), "{msg}");
+
+        fuzz(200, &["1,2\n3,4"], |input| {
+            let _ = input.split(',').map(str::parse::<i32>).collect::<Vec<_>>();
+        });
+    }
+}
@@ -0,0 +1,329 @@
+//! Animating a day's simulation in the terminal.
+//!
+//! A day that implements [`Visualize`] replays its simulation, drawing a
+//! [`Frame`] per step and handing it to the [`Screen`], which redraws the
+//! terminal in place and waits between frames. Its binary runs the animation
+//! when given `--visualize [ms]`, before printing the answers as usual. The
+//! animation is drawn on stderr, so stdout holds just the answers, in whatever
+//! `--format` they were asked for.
+//!
+//! Given `--export <path>` instead, the screen writes each frame to a
+//! numbered image file, `sand.png` becoming `sand-0001.png` and so on. An SVG
+//! is a picture rather than an animation, so `sand.svg` gets just the last
+//! frame, and `--frame <n>` picks frame `n` instead, for any format.
+use std::{
+    io::{self, Write},
+    path::{Path, PathBuf},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    grid::Coord,
+    image::{Image, Rgb},
+    Result,
+};
+
+/// The time between frames when `--visualize` isn't given one.
+pub const DEFAULT_DELAY: Duration = Duration::from_millis(30);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Colour {
+    #[default]
+    Plain,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Grey,
+    /// Any colour, for terminals that take 24 bit colour.
+    Rgb(u8, u8, u8),
+}
+
+impl Colour {
+    /// The ANSI escape code that switches to the colour.
+    fn code(self) -> String {
+        match self {
+            Colour::Plain => "\x1b[0m".to_string(),
+            Colour::Red => "\x1b[31m".to_string(),
+            Colour::Green => "\x1b[32m".to_string(),
+            Colour::Yellow => "\x1b[33m".to_string(),
+            Colour::Blue => "\x1b[34m".to_string(),
+            Colour::Magenta => "\x1b[35m".to_string(),
+            Colour::Cyan => "\x1b[36m".to_string(),
+            Colour::Grey => "\x1b[90m".to_string(),
+            Colour::Rgb(r, g, b) => format!("\x1b[38;2;{r};{g};{b}m"),
+        }
+    }
+
+    /// Roughly how a terminal shows the colour, for images.
+    pub fn rgb(self) -> Rgb {
+        match self {
+            Colour::Plain => Rgb(204, 204, 204),
+            Colour::Red => Rgb(205, 49, 49),
+            Colour::Green => Rgb(13, 188, 121),
+            Colour::Yellow => Rgb(229, 229, 16),
+            Colour::Blue => Rgb(36, 114, 200),
+            Colour::Magenta => Rgb(188, 63, 188),
+            Colour::Cyan => Rgb(17, 168, 205),
+            Colour::Grey => Rgb(102, 102, 102),
+            Colour::Rgb(r, g, b) => Rgb(r, g, b),
+        }
+    }
+}
+
+impl From<Rgb> for Colour {
+    fn from(Rgb(r, g, b): Rgb) -> Self {
+        Colour::Rgb(r, g, b)
+    }
+}
+
+/// A picture of the area between two corners, one coloured character per
+/// cell, with a line of caption above it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    min: Coord,
+    width: usize,
+    cells: Vec<(char, Colour)>,
+    caption: String,
+}
+
+impl Frame {
+    /// A blank frame covering `min` to `max`, both included.
+    pub fn new(min: Coord, max: Coord) -> Self {
+        let width = (max.x - min.x + 1).max(0) as usize;
+        let height = (max.y - min.y + 1).max(0) as usize;
+        Self {
+            min,
+            width,
+            cells: vec![(' ', Colour::Plain); width * height],
+            caption: String::new(),
+        }
+    }
+
+    /// Draws `c` at `p`. Points outside the frame are left out.
+    pub fn set(&mut self, p: Coord, c: char, colour: Colour) {
+        let (x, y) = (p.x - self.min.x, p.y - self.min.y);
+        if x < 0 || y < 0 || x as usize >= self.width {
+            return;
+        }
+        if let Some(cell) = self.cells.get_mut(y as usize * self.width + x as usize) {
+            *cell = (c, colour);
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.len() / self.width.max(1)
+    }
+
+    /// The cell `x` across and `y` down from the frame's top left corner.
+    pub fn cell(&self, x: usize, y: usize) -> (char, Colour) {
+        self.cells[y * self.width + x]
+    }
+
+    pub fn set_caption(&mut self, caption: impl Into<String>) {
+        self.caption = caption.into();
+    }
+
+    /// The frame as text, with escape codes wherever the colour changes.
+    pub fn render(&self) -> String {
+        let mut out = format!("{}\n", self.caption);
+        for row in self.cells.chunks(self.width.max(1)) {
+            let mut colour = Colour::Plain;
+            for &(c, next) in row {
+                if next != colour {
+                    out.push_str(&next.code());
+                    colour = next;
+                }
+                out.push(c);
+            }
+            if colour != Colour::Plain {
+                out.push_str(&Colour::Plain.code());
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Pixels per cell in exported rasters, units in SVGs.
+const EXPORT_SCALE: usize = 4;
+
+/// The terminal, redrawn in place for each frame, or a series of image files.
+#[derive(Debug)]
+pub struct Screen {
+    delay: Duration,
+    frames: usize,
+    export: Option<PathBuf>,
+    /// For an export to a single image, the frame it's of, or `None` for the
+    /// last, and the frame itself once shown.
+    single: Option<(Option<usize>, Option<Frame>)>,
+    /// The first frame that couldn't be written; the rest are skipped.
+    error: Option<String>,
+}
+
+impl Screen {
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            delay,
+            frames: 0,
+            export: None,
+            single: None,
+            error: None,
+        }
+    }
+
+    /// A screen that writes each frame to an image named after `path`, in
+    /// the format its extension names, or just the last frame to `path` if
+    /// it's an SVG.
+    pub fn export(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let svg = path.extension().is_some_and(|e| e == "svg");
+        Self {
+            export: Some(path),
+            single: svg.then_some((None, None)),
+            ..Self::new(Duration::ZERO)
+        }
+    }
+
+    /// Exports only frame `n`, counting from 1, to the path itself.
+    pub fn only_frame(mut self, n: usize) -> Self {
+        self.single = Some((Some(n), None));
+        self
+    }
+
+    /// Draws `frame` over the last one and waits for the frame delay, or
+    /// writes it to the next image file.
+    pub fn show(&mut self, frame: &Frame) {
+        if let Some((n, kept)) = &mut self.single {
+            self.frames += 1;
+            if n.is_none_or(|n| n == self.frames) {
+                match kept {
+                    Some(kept) => kept.clone_from(frame),
+                    None => *kept = Some(frame.clone()),
+                }
+            }
+            return;
+        }
+
+        if let Some(path) = &self.export {
+            if self.error.is_none() {
+                let path = numbered(path, self.frames + 1);
+                if let Err(e) = Image::from_frame(frame).save(&path, EXPORT_SCALE) {
+                    self.error = Some(e.to_string());
+                }
+            }
+            self.frames += 1;
+            return;
+        }
+
+        // home the cursor, clearing the whole screen only the first time so
+        // later frames don't flicker
+        let clear = if self.frames == 0 { "\x1b[2J" } else { "" };
+        let mut out = io::stderr().lock();
+        // a closed stderr just means nobody is watching
+        let _ = write!(out, "{clear}\x1b[H{}\x1b[J", frame.render());
+        let _ = out.flush();
+
+        self.frames += 1;
+        thread::sleep(self.delay);
+    }
+
+    /// How many frames have been shown.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Writes the single image, if that's what is being exported, and reports
+    /// a frame that couldn't be.
+    pub fn finish(self) -> Result<()> {
+        if let (Some(path), Some((n, kept))) = (&self.export, self.single) {
+            let Some(frame) = kept else {
+                let n = n.unwrap_or(1);
+                return Err(format!("there's no frame {n}, only {}", self.frames).into());
+            };
+            return Image::from_frame(&frame).save(path, EXPORT_SCALE);
+        }
+
+        match self.error {
+            Some(e) => Err(e.into()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// `dir/name-0001.png` for frame 1 of `dir/name.png`.
+fn numbered(path: &Path, frame: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let mut name = format!("{stem}-{frame:04}");
+    if let Some(ext) = path.extension() {
+        name = format!("{name}.{}", ext.to_string_lossy());
+    }
+    path.with_file_name(name)
+}
+
+/// A puzzle whose simulation can be watched.
+pub trait Visualize {
+    /// Replays the simulation, showing a frame on `screen` for each step.
+    fn visualize(&self, screen: &mut Screen);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame() {
+        let mut frame = Frame::new(Coord::new(-1, 0), Coord::new(1, 1));
+        frame.set(Coord::new(-1, 0), '#', Colour::Plain);
+        frame.set(Coord::new(0, 1), 'o', Colour::Yellow);
+        frame.set(Coord::new(5, 5), 'x', Colour::Red);
+        frame.set_caption("sand");
+
+        assert_eq!(frame.render(), "sand\n#  \n \x1b[33mo\x1b[0m \n");
+    }
+
+    #[test]
+    fn test_export_one_frame() {
+        let dir = std::env::temp_dir().join(format!("aoc-export-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let frames: Vec<Frame> = (0..3)
+            .map(|x| {
+                let mut frame = Frame::new(Coord::new(0, 0), Coord::new(2, 0));
+                frame.set(Coord::new(x, 0), '#', Colour::Red);
+                frame
+            })
+            .collect();
+        let export = |screen: &mut Screen| frames.iter().for_each(|f| screen.show(f));
+        let svg = |frame: &Frame| Image::from_frame(frame).to_svg(EXPORT_SCALE);
+
+        let path = dir.join("last.svg");
+        let mut screen = Screen::export(&path);
+        export(&mut screen);
+        screen.finish().unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), svg(&frames[2]));
+
+        let path = dir.join("second.svg");
+        let mut screen = Screen::export(&path).only_frame(2);
+        export(&mut screen);
+        screen.finish().unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), svg(&frames[1]));
+
+        let mut screen = Screen::export(dir.join("none.png")).only_frame(4);
+        export(&mut screen);
+        assert!(screen.finish().is_err());
+
+        let names: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name())
+            .collect();
+        assert_eq!(names.len(), 2, "{names:?}");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
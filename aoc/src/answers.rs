@@ -0,0 +1,134 @@
+//! The expected answers manifest, `answers.toml` at the workspace root.
+//!
+//! Only the small slice of TOML the manifest needs is understood: a table per
+//! day and input name, holding integer or string values for each part.
+//!
+//! ```toml
+//! [day10.sample]
+//! part1 = 13140
+//! part2 = """
+//! ##..##..
+//! ###...##"""
+//! ```
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+use aoc_common::Result;
+
+#[derive(Debug, Default)]
+pub struct Answers {
+    entries: BTreeMap<(u8, String, u8), String>,
+}
+
+impl Answers {
+    pub fn path() -> PathBuf {
+        crate::workspace_dir().join("answers.toml")
+    }
+
+    /// Reads the manifest from the workspace root.
+    pub fn load() -> Result<Self> {
+        let path = Self::path();
+        let text = fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+        Self::parse(&text)
+    }
+
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut answers = Answers::default();
+        let mut table: Option<(u8, String)> = None;
+        let mut lines = text.lines().enumerate();
+
+        while let Some((idx, line)) = lines.next() {
+            let line_no = idx + 1;
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let header = header
+                    .strip_suffix(']')
+                    .ok_or(format!("line {line_no}: unterminated table header"))?;
+                let (day, input) = header
+                    .split_once('.')
+                    .ok_or(format!("line {line_no}: expected [dayNN.input]"))?;
+                let day = day
+                    .strip_prefix("day")
+                    .and_then(|d| d.parse().ok())
+                    .ok_or(format!("line {line_no}: expected dayNN, found {day}"))?;
+                table = Some((day, input.to_string()));
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(format!("line {line_no}: expected key = value"))?;
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                key => return Err(format!("line {line_no}: unknown key {key}").into()),
+            };
+            let (day, input) = table.clone().ok_or(format!(
+                "line {line_no}: answer outside of a [dayNN.input] table"
+            ))?;
+
+            let value = value.trim();
+            let value = if let Some(rest) = value.strip_prefix("\"\"\"") {
+                // multi-line string, the newline straight after the quotes is dropped
+                let mut text = String::new();
+                let mut rest = rest.to_string();
+                loop {
+                    if let Some(end) = rest.find("\"\"\"") {
+                        text.push_str(&rest[..end]);
+                        break;
+                    }
+                    text.push_str(&rest);
+                    text.push('\n');
+                    rest = match lines.next() {
+                        Some((_, l)) => l.to_string(),
+                        None => return Err(format!("line {line_no}: unterminated string").into()),
+                    };
+                }
+                text.strip_prefix('\n').unwrap_or(&text).to_string()
+            } else if let Some(rest) = value.strip_prefix('"') {
+                rest.strip_suffix('"')
+                    .ok_or(format!("line {line_no}: unterminated string"))?
+                    .to_string()
+            } else {
+                let n: i64 = value
+                    .replace('_', "")
+                    .parse()
+                    .map_err(|_| format!("line {line_no}: expected a number or a string"))?;
+                n.to_string()
+            };
+
+            answers.entries.insert((day, input, part), value);
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, day: u8, input: &str, part: u8) -> Option<&str> {
+        self.entries
+            .get(&(day, input.to_string(), part))
+            .map(String::as_str)
+    }
+
+    /// Every input name with at least one recorded answer for `day`.
+    pub fn inputs(&self, day: u8) -> Vec<&str> {
+        let mut inputs: Vec<&str> = self
+            .entries
+            .keys()
+            .filter(|(d, _, _)| *d == day)
+            .map(|(_, input, _)| input.as_str())
+            .collect();
+        inputs.dedup();
+        inputs
+    }
+}
+
+fn strip_comment(line: &str) -> &str {
+    // a '#' after an opening quote belongs to the string
+    match line.find('#') {
+        Some(idx) if !line[..idx].contains('"') => &line[..idx],
+        _ => line,
+    }
+}
@@ -0,0 +1,212 @@
+//! Timing for `aoc bench`: parse, part 1 and part 2 are each run repeatedly
+//! and summarised as min/median/max.
+//!
+//! Reports are written as JSON so a run can be saved and used as the baseline
+//! for a later one. Only the flat records written by [`to_json`] are read back.
+//! [`to_csv`] writes the same fields, for spreadsheets.
+use std::{
+    fmt,
+    hint::black_box,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use aoc_common::Result;
+
+use crate::registry::Day;
+
+/// A stage stops repeating once it has used this much time, so a slow day
+/// still finishes in a reasonable time with a handful of runs.
+const BUDGET: Duration = Duration::from_secs(5);
+
+/// Changes smaller than this are noise, whatever the percentage says.
+const NOISE: Duration = Duration::from_millis(1);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part1 => write!(f, "part1"),
+            Stage::Part2 => write!(f, "part2"),
+        }
+    }
+}
+
+impl FromStr for Stage {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Stage::Parse),
+            "part1" => Ok(Stage::Part1),
+            "part2" => Ok(Stage::Part2),
+            _ => Err(format!("unknown stage: {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timing {
+    pub day: u8,
+    pub stage: Stage,
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    fn new(day: u8, stage: Stage, mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Self {
+            day,
+            stage,
+            runs: samples.len(),
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+
+    /// The change in median against `baseline`, as a percentage.
+    pub fn change(&self, baseline: &Timing) -> f64 {
+        let base = baseline.median.as_secs_f64();
+        if base == 0.0 {
+            return 0.0;
+        }
+        (self.median.as_secs_f64() - base) / base * 100.0
+    }
+
+    /// Slower than `baseline` by more than `threshold` percent, and by enough
+    /// not to be noise.
+    pub fn regressed(&self, baseline: &Timing, threshold: f64) -> bool {
+        self.change(baseline) > threshold && self.median > baseline.median + NOISE
+    }
+}
+
+/// Runs `f` up to `runs` times, stopping early once the time budget is spent.
+fn measure(runs: usize, mut f: impl FnMut()) -> Vec<Duration> {
+    let mut samples = vec![];
+    let started = Instant::now();
+    while samples.len() < runs.max(1) {
+        let start = Instant::now();
+        f();
+        samples.push(start.elapsed());
+
+        if started.elapsed() > BUDGET {
+            break;
+        }
+    }
+
+    samples
+}
+
+pub fn bench(day: &Day, input: &str, runs: usize) -> Result<Vec<Timing>> {
+    // parse once up front so a bad input is an error rather than a panic
+    let solution = (day.parse)(input)?;
+
+    let parse = measure(runs, || {
+        let _ = black_box((day.parse)(black_box(input)));
+    });
+    let part1 = measure(runs, || {
+        black_box(solution.part1());
+    });
+    let part2 = measure(runs, || {
+        black_box(solution.part2());
+    });
+
+    Ok(vec![
+        Timing::new(day.number, Stage::Parse, parse),
+        Timing::new(day.number, Stage::Part1, part1),
+        Timing::new(day.number, Stage::Part2, part2),
+    ])
+}
+
+pub fn to_json(timings: &[Timing]) -> String {
+    let records: Vec<String> = timings
+        .iter()
+        .map(|t| {
+            format!(
+                "  {{\"day\": {}, \"stage\": \"{}\", \"runs\": {}, \"min_ns\": {}, \"median_ns\": {}, \"max_ns\": {}}}",
+                t.day,
+                t.stage,
+                t.runs,
+                t.min.as_nanos(),
+                t.median.as_nanos(),
+                t.max.as_nanos()
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]\n", records.join(",\n"))
+}
+
+pub fn to_csv(timings: &[Timing]) -> String {
+    let mut out = "day,stage,runs,min_ns,median_ns,max_ns\n".to_string();
+    for t in timings {
+        out += &format!(
+            "{},{},{},{},{},{}\n",
+            t.day,
+            t.stage,
+            t.runs,
+            t.min.as_nanos(),
+            t.median.as_nanos(),
+            t.max.as_nanos()
+        );
+    }
+    out
+}
+
+pub fn from_json(text: &str) -> Result<Vec<Timing>> {
+    let mut timings = vec![];
+    for record in text.split('{').skip(1) {
+        let record = record
+            .split_once('}')
+            .ok_or("unterminated record in baseline")?
+            .0;
+
+        let mut day = None;
+        let mut stage = None;
+        let mut numbers = [None; 4];
+        for field in record.split(',') {
+            let (key, value) = field
+                .split_once(':')
+                .ok_or(format!("expected \"key\": value, found {field}"))?;
+            let value = value.trim().trim_matches('"');
+            let number = || {
+                value
+                    .parse::<u64>()
+                    .map_err(|_| format!("bad number: {value}"))
+            };
+            match key.trim().trim_matches('"') {
+                "day" => day = Some(number()? as u8),
+                "stage" => stage = Some(value.parse::<Stage>()?),
+                "runs" => numbers[0] = Some(number()?),
+                "min_ns" => numbers[1] = Some(number()?),
+                "median_ns" => numbers[2] = Some(number()?),
+                "max_ns" => numbers[3] = Some(number()?),
+                _ => {}
+            }
+        }
+
+        let missing = || format!("incomplete record in baseline: {{{record}}}");
+        let [runs, min, median, max] = numbers.map(|n| n.ok_or_else(missing));
+        timings.push(Timing {
+            day: day.ok_or_else(missing)?,
+            stage: stage.ok_or_else(missing)?,
+            runs: runs? as usize,
+            min: Duration::from_nanos(min?),
+            median: Duration::from_nanos(median?),
+            max: Duration::from_nanos(max?),
+        });
+    }
+
+    Ok(timings)
+}
@@ -0,0 +1,19 @@
+//! The pieces of the `aoc` runner shared with its tests: the registry of day
+//! solutions, the expected answers manifest, benchmarking, scaffolding new
+//! days, and fetching from and submitting to the website.
+pub mod answers;
+pub mod bench;
+pub mod registry;
+pub mod scaffold;
+pub mod site;
+pub mod submit;
+
+use std::path::{Path, PathBuf};
+
+/// The root of the Cargo workspace, where the day crates live.
+pub fn workspace_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the aoc crate lives inside the workspace")
+        .to_path_buf()
+}
@@ -0,0 +1,507 @@
+//! One binary for every day's solution.
+//!
+//! `aoc run 17 --part 2 --input path` runs a single day, `aoc run all` runs the
+//! lot against their own `input/input.txt` (or `--sample`), and both finish
+//! with a table of answers and how long each part took. The parts are solved
+//! side by side on a thread per CPU (or `--jobs n`), and one that panics is
+//! reported as a failure without stopping the rest.
+//!
+//! `aoc bench [day]` times parse, part 1 and part 2 over repeated runs, and
+//! can save the report as a baseline to compare later runs against.
+//!
+//! `aoc new <day>` creates the crate for a new day, `aoc fetch <day|all>`
+//! downloads puzzle inputs from the website and `aoc submit <day> <part>`
+//! sends an answer back.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    bench::{self, Timing},
+    registry::{self, Day},
+    scaffold,
+    site::{self, Client},
+    submit::{self, Log, Outcome},
+};
+use aoc_common::{
+    diag, pool,
+    report::{self, Format, Record},
+    Input, Result,
+};
+
+const USAGE: &str = "\
+usage: aoc run <day|all> [--part <1|2>] [--sample [name]] [--input <path>]
+               [--jobs <n>] [--format <json|csv|text>] [-v|-vv]
+       aoc bench [day] [--input <path>] [--runs <n>] [--format <json|csv|text>]
+                 [--save <path>] [--baseline <path>] [--threshold <percent>]
+       aoc new <day>
+       aoc fetch <day|all>
+       aoc submit <day> <1|2> [--input <path>]
+
+A single day reads its puzzle input from --input, or stdin when it is not
+given. `all` reads each day's own input/input.txt. --sample reads the day's
+input/sample.txt instead, or input/<name>.txt when a name such as sample2 is
+given, for one day or all of them. --jobs sets how many parts are solved at
+once, one per CPU by default. --format json or csv prints a record per part
+instead of the table, and -v or -vv shows the solutions' diagnostics on
+stderr. `bench` always reads a file, each day's
+input/input.txt unless --input is given. `new` creates aocNN with stub
+solutions and empty inputs, and refuses to touch a day that already exists.
+`fetch` downloads each day's input/input.txt unless it is already there,
+using the session token in AOC_SESSION or ~/.config/aoc/session. `submit`
+solves a part on the day's input/input.txt and posts the answer, unless
+submissions.log shows it can't be right.";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("new") => new(&args[1..]),
+        Some("fetch") => fetch(&args[1..]),
+        Some("submit") => submit(&args[1..]),
+        _ => Err(USAGE.into()),
+    };
+
+    if let Err(e) = result {
+        eprintln!("{e}");
+        process::exit(1);
+    }
+}
+
+struct RunArgs {
+    days: Vec<&'static Day>,
+    part: Option<u8>,
+    input: Option<Input>,
+    jobs: usize,
+    format: Format,
+}
+
+impl RunArgs {
+    fn parse(args: &[String]) -> Result<Self> {
+        let mut run = RunArgs {
+            days: vec![],
+            part: None,
+            input: None,
+            jobs: pool::workers(),
+            format: Format::Text,
+        };
+        let mut args = args.iter().peekable();
+
+        match args.next() {
+            Some(day) => run.days = select_days(day)?,
+            None => return Err(USAGE.into()),
+        }
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--part" | "-p" => {
+                    let part = args.next().ok_or("--part needs a value")?;
+                    run.part = match part.as_str() {
+                        "1" => Some(1),
+                        "2" => Some(2),
+                        _ => return Err(format!("invalid part: {part}").into()),
+                    };
+                }
+                "--input" | "-i" => {
+                    let path = args.next().ok_or("--input needs a path")?;
+                    run.input = Some(Input::Path(path.into()));
+                }
+                "--sample" | "-s" => {
+                    let name = args.next_if(|a| !a.starts_with('-'));
+                    run.input = Some(Input::Named(name.map_or("sample", |n| n).to_string()));
+                }
+                "--format" | "-f" => {
+                    run.format = args.next().ok_or("--format needs a value")?.parse()?;
+                }
+                "--verbose" | "-v" => diag::set_verbosity(diag::verbosity() + 1),
+                "-vv" => diag::set_verbosity(2),
+                "--jobs" | "-j" => {
+                    let jobs = args.next().ok_or("--jobs needs a value")?;
+                    run.jobs = match jobs.parse() {
+                        Ok(jobs) if jobs > 0 => jobs,
+                        _ => return Err(format!("invalid jobs: {jobs}").into()),
+                    };
+                }
+                _ => return Err(format!("unrecognized argument: {arg}\n\n{USAGE}").into()),
+            }
+        }
+
+        if matches!(run.input, Some(Input::Path(_))) && run.days.len() > 1 {
+            return Err("--input can only be used with a single day".into());
+        }
+
+        Ok(run)
+    }
+
+    fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+/// `all`, or a single day number.
+fn select_days(arg: &str) -> Result<Vec<&'static Day>> {
+    if arg == "all" {
+        return Ok(registry::DAYS.iter().collect());
+    }
+
+    let number: u8 = arg.parse().map_err(|_| format!("invalid day: {arg}"))?;
+    let day = registry::find(number).ok_or(format!("no solution for day {number}"))?;
+    Ok(vec![day])
+}
+
+fn run(args: &[String]) -> Result<()> {
+    let args = RunArgs::parse(args)?;
+    let start = Instant::now();
+
+    let mut inputs = vec![];
+    for day in &args.days {
+        let input = match &args.input {
+            Some(input) => input.read(&day.dir()),
+            None if args.days.len() == 1 => Input::Stdin.read(&day.dir()),
+            None => Input::Named("input".into()).read(&day.dir()),
+        };
+
+        match input {
+            Ok(input) => inputs.push(Ok(input)),
+            Err(e) if args.days.len() == 1 => return Err(e),
+            // keep going so one missing input doesn't hide the other days
+            Err(e) => inputs.push(Err(format!("error: {e}"))),
+        }
+    }
+
+    let jobs: Vec<_> = args
+        .days
+        .iter()
+        .zip(&inputs)
+        .flat_map(|(day, input)| {
+            args.parts()
+                .into_iter()
+                .map(move |part| (*day, part, input))
+        })
+        .collect();
+    let results =
+        pool::map_catching(
+            jobs.iter().copied(),
+            args.jobs,
+            |(day, part, input)| match input {
+                Ok(input) => solve(day, part, input),
+                Err(e) => failure(day, part, e.clone(), 0),
+            },
+        );
+    let records: Vec<Record> = jobs
+        .iter()
+        .zip(results)
+        .map(|(&(day, part, input), result)| {
+            result.unwrap_or_else(|msg| {
+                let hash = input.as_ref().map_or(0, |i| report::input_hash(i));
+                failure(day, part, format!("panicked: {msg}"), hash)
+            })
+        })
+        .collect();
+
+    match args.format {
+        Format::Text => {
+            print_table(&records);
+            println!("Total: {:.2?}", start.elapsed());
+        }
+        Format::Json => print!("{}", report::to_json(&records)),
+        Format::Csv => print!("{}", report::to_csv(&records)),
+    }
+
+    let failed = records.iter().filter(|r| !r.ok).count();
+    if failed > 0 {
+        return Err(format!("{failed} part(s) failed").into());
+    }
+
+    Ok(())
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()).into())
+}
+
+/// Parses the input and answers one part. The elapsed time covers both, so
+/// each part is timed as if it were run on its own.
+fn solve(day: &Day, part: u8, input: &str) -> Record {
+    let start = Instant::now();
+    let answer = (day.parse)(input).map(|solution| match part {
+        1 => solution.part1(),
+        _ => solution.part2(),
+    });
+
+    Record {
+        day: day.number,
+        part,
+        ok: answer.is_ok(),
+        answer: match answer {
+            Ok(answer) => answer.to_string(),
+            Err(e) => format!("error: {e}"),
+        },
+        elapsed: start.elapsed(),
+        input_hash: report::input_hash(input),
+    }
+}
+
+fn failure(day: &Day, part: u8, answer: String, input_hash: u64) -> Record {
+    Record {
+        day: day.number,
+        part,
+        answer,
+        ok: false,
+        elapsed: Duration::ZERO,
+        input_hash,
+    }
+}
+
+fn print_table(rows: &[Record]) {
+    let width = rows
+        .iter()
+        .flat_map(|r| r.answer.lines())
+        .map(str::len)
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!(
+        "{:>3}  {:>4}  {:<width$}  {:>10}",
+        "Day", "Part", "Answer", "Elapsed"
+    );
+    for row in rows {
+        // multi-line answers (the day 10 screen) continue under the first line
+        let mut lines = row.answer.lines();
+        let first = lines.next().unwrap_or_default();
+        let elapsed = format!("{:.2?}", row.elapsed);
+        println!(
+            "{:>3}  {:>4}  {first:<width$}  {elapsed:>10}",
+            row.day, row.part
+        );
+        for line in lines {
+            println!("{:>3}  {:>4}  {line}", "", "");
+        }
+    }
+}
+
+struct BenchArgs {
+    days: Vec<&'static Day>,
+    input: Option<PathBuf>,
+    runs: usize,
+    format: Format,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+}
+
+impl BenchArgs {
+    fn parse(args: &[String]) -> Result<Self> {
+        let mut bench = BenchArgs {
+            days: registry::DAYS.iter().collect(),
+            input: None,
+            runs: 10,
+            format: Format::Text,
+            save: None,
+            baseline: None,
+            threshold: 10.0,
+        };
+
+        let mut args = args.iter().peekable();
+        if let Some(day) = args.next_if(|a| !a.starts_with('-')) {
+            bench.days = select_days(day)?;
+        }
+
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{arg} needs a value"));
+            match arg.as_str() {
+                "--input" | "-i" => bench.input = Some(value()?.into()),
+                "--runs" | "-n" => {
+                    let runs = value()?;
+                    bench.runs = runs.parse().map_err(|_| format!("invalid runs: {runs}"))?;
+                }
+                "--format" | "-f" => bench.format = value()?.parse()?,
+                "--save" => bench.save = Some(value()?.into()),
+                "--baseline" => bench.baseline = Some(value()?.into()),
+                "--threshold" => {
+                    let threshold = value()?;
+                    bench.threshold = threshold
+                        .parse()
+                        .map_err(|_| format!("invalid threshold: {threshold}"))?;
+                }
+                _ => return Err(format!("unrecognized argument: {arg}\n\n{USAGE}").into()),
+            }
+        }
+
+        if bench.input.is_some() && bench.days.len() > 1 {
+            return Err("--input can only be used with a single day".into());
+        }
+
+        Ok(bench)
+    }
+}
+
+fn bench(args: &[String]) -> Result<()> {
+    let args = BenchArgs::parse(args)?;
+    let baseline = match &args.baseline {
+        Some(path) => bench::from_json(&read_file(path)?)?,
+        None => vec![],
+    };
+
+    let mut timings = vec![];
+    for day in &args.days {
+        let input = match &args.input {
+            Some(path) => read_file(path)?,
+            None => read_file(&day.input_path("input"))?,
+        };
+        timings.extend(bench::bench(day, &input, args.runs)?);
+    }
+
+    if let Some(path) = &args.save {
+        fs::write(path, bench::to_json(&timings))
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+    }
+
+    match args.format {
+        Format::Text => print_bench_table(&timings, &baseline, args.threshold),
+        Format::Json => print!("{}", bench::to_json(&timings)),
+        Format::Csv => print!("{}", bench::to_csv(&timings)),
+    }
+
+    let regressions = timings
+        .iter()
+        .filter(|t| find_baseline(&baseline, t).is_some_and(|b| t.regressed(b, args.threshold)))
+        .count();
+    if regressions > 0 {
+        return Err(format!("{regressions} stage(s) regressed against the baseline").into());
+    }
+
+    Ok(())
+}
+
+fn find_baseline<'a>(baseline: &'a [Timing], timing: &Timing) -> Option<&'a Timing> {
+    baseline
+        .iter()
+        .find(|b| b.day == timing.day && b.stage == timing.stage)
+}
+
+fn print_bench_table(timings: &[Timing], baseline: &[Timing], threshold: f64) {
+    print!(
+        "{:>3}  {:<5}  {:>4}  {:>10}  {:>10}  {:>10}",
+        "Day", "Stage", "Runs", "Min", "Median", "Max"
+    );
+    if baseline.is_empty() {
+        println!();
+    } else {
+        println!("  {:>8}", "Baseline");
+    }
+
+    for t in timings {
+        let min = format!("{:.2?}", t.min);
+        let median = format!("{:.2?}", t.median);
+        let max = format!("{:.2?}", t.max);
+        print!(
+            "{:>3}  {:<5}  {:>4}  {min:>10}  {median:>10}  {max:>10}",
+            t.day, t.stage, t.runs
+        );
+
+        match find_baseline(baseline, t) {
+            Some(b) if t.regressed(b, threshold) => {
+                println!("  {:>+7.1}%  REGRESSION", t.change(b))
+            }
+            Some(b) => println!("  {:>+7.1}%", t.change(b)),
+            None => println!(),
+        }
+    }
+}
+
+fn new(args: &[String]) -> Result<()> {
+    let [day] = args else {
+        return Err(USAGE.into());
+    };
+    let number: u8 = day.parse().map_err(|_| format!("invalid day: {day}"))?;
+
+    let root = aoc::workspace_dir();
+    let new = scaffold::new_day(&root, number)?;
+    let relative = |path: &PathBuf| {
+        path.strip_prefix(&root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    };
+    for path in &new.created {
+        println!("created {}", relative(path));
+    }
+    for path in &new.updated {
+        println!("updated {}", relative(path));
+    }
+    println!(
+        "fill in the day's answers in answers.toml and its sample test as it's \
+         solved; until then they expect it to be unsolved"
+    );
+
+    Ok(())
+}
+
+fn fetch(args: &[String]) -> Result<()> {
+    let [day] = args else {
+        return Err(USAGE.into());
+    };
+    let days = select_days(day)?;
+
+    // the session is only needed when something has to be downloaded
+    let mut client = None;
+    for day in days {
+        let path = day.input_path("input");
+        if site::is_cached(&path) {
+            println!("day {}: already have {}", day.number, path.display());
+            continue;
+        }
+
+        let client = match &mut client {
+            Some(client) => client,
+            None => client.insert(Client::from_env()?),
+        };
+        site::fetch_input(client, day.number, &path)?;
+        println!("day {}: downloaded {}", day.number, path.display());
+    }
+
+    Ok(())
+}
+
+fn submit(args: &[String]) -> Result<()> {
+    let (day, part, input) = match args {
+        [day, part] => (day, part, Input::Named("input".into())),
+        [day, part, flag, path] if flag == "--input" || flag == "-i" => {
+            (day, part, Input::Path(path.into()))
+        }
+        _ => return Err(USAGE.into()),
+    };
+    let [day] = select_days(day)?[..] else {
+        return Err("submit takes a single day".into());
+    };
+    let part = match part.as_str() {
+        "1" => 1,
+        "2" => 2,
+        _ => return Err(format!("invalid part: {part}").into()),
+    };
+
+    let row = solve(day, part, &input.read(&day.dir())?);
+    if !row.ok {
+        return Err(row.answer.into());
+    }
+    println!("day {} part {part}: {}", day.number, row.answer);
+
+    let mut log = Log::load(&Log::default_path())?;
+    let mut client = Client::from_env()?;
+    let outcome = submit::submit(&mut client, &mut log, day.number, part, &row.answer)?;
+    println!("{outcome}");
+
+    match outcome {
+        Outcome::Correct => Ok(()),
+        _ => Err(format!("the answer was not accepted ({outcome})").into()),
+    }
+}
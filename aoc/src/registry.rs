@@ -0,0 +1,134 @@
+use std::path::PathBuf;
+
+use aoc_common::{Result, Solution};
+
+/// Parses a day's input into a solution that can answer either part.
+pub type Parser = fn(&str) -> Result<Box<dyn Solution>>;
+
+pub struct Day {
+    pub number: u8,
+    pub parse: Parser,
+}
+
+impl Day {
+    /// The day's crate, `aocNN`.
+    pub fn dir(&self) -> PathBuf {
+        crate::workspace_dir().join(format!("aoc{:02}", self.number))
+    }
+
+    /// One of the day's puzzle inputs, `aocNN/input/<name>.txt`.
+    pub fn input_path(&self, name: &str) -> PathBuf {
+        self.dir().join("input").join(format!("{name}.txt"))
+    }
+}
+
+fn parse<S: Solution + 'static>(input: &str) -> Result<Box<dyn Solution>> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        parse: parse::<aoc01::Day01>,
+    },
+    Day {
+        number: 2,
+        parse: parse::<aoc02::Day02>,
+    },
+    Day {
+        number: 3,
+        parse: parse::<aoc03::Day03>,
+    },
+    Day {
+        number: 4,
+        parse: parse::<aoc04::Day04>,
+    },
+    Day {
+        number: 5,
+        parse: parse::<aoc05::Day05>,
+    },
+    Day {
+        number: 6,
+        parse: parse::<aoc06::Day06>,
+    },
+    Day {
+        number: 7,
+        parse: parse::<aoc07::Day07>,
+    },
+    Day {
+        number: 8,
+        parse: parse::<aoc08::Day08>,
+    },
+    Day {
+        number: 9,
+        parse: parse::<aoc09::Day09>,
+    },
+    Day {
+        number: 10,
+        parse: parse::<aoc10::Day10>,
+    },
+    Day {
+        number: 11,
+        parse: parse::<aoc11::Day11>,
+    },
+    Day {
+        number: 12,
+        parse: parse::<aoc12::Day12>,
+    },
+    Day {
+        number: 13,
+        parse: parse::<aoc13::Day13>,
+    },
+    Day {
+        number: 14,
+        parse: parse::<aoc14::Day14>,
+    },
+    Day {
+        number: 15,
+        parse: parse::<aoc15::Day15>,
+    },
+    Day {
+        number: 16,
+        parse: parse::<aoc16::Day16>,
+    },
+    Day {
+        number: 17,
+        parse: parse::<aoc17::Day17>,
+    },
+    Day {
+        number: 18,
+        parse: parse::<aoc18::Day18>,
+    },
+    Day {
+        number: 19,
+        parse: parse::<aoc19::Day19>,
+    },
+    Day {
+        number: 20,
+        parse: parse::<aoc20::Day20>,
+    },
+    Day {
+        number: 21,
+        parse: parse::<aoc21::Day21>,
+    },
+    Day {
+        number: 22,
+        parse: parse::<aoc22::Day22>,
+    },
+    Day {
+        number: 23,
+        parse: parse::<aoc23::Day23>,
+    },
+    Day {
+        number: 24,
+        parse: parse::<aoc24::Day24>,
+    },
+    Day {
+        number: 25,
+        parse: parse::<aoc25::Day25>,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
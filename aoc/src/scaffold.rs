@@ -0,0 +1,345 @@
+//! `aoc new <day>`: a fresh day crate with stub parse, part 1 and part 2 and
+//! empty inputs to paste the puzzle into, wired into the workspace, the `aoc`
+//! registry and the answers and fuzz suites.
+use std::{
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+};
+
+use aoc_common::Result;
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.in");
+const MAIN_RS: &str = include_str!("../templates/main.rs.in");
+const LIB_RS: &str = include_str!("../templates/lib.rs.in");
+
+/// What [`new_day`] wrote.
+#[derive(Debug, Default)]
+pub struct NewDay {
+    pub created: Vec<PathBuf>,
+    pub updated: Vec<PathBuf>,
+}
+
+/// Rewrites one of the shared files to take in a new day.
+type Edit = fn(&str, u8) -> Result<String>;
+
+/// Creates `aocNN` under `root` and registers it everywhere a day has to be
+/// named: the workspace, `aoc`'s dependencies and registry, the answers and
+/// fuzz suites and `answers.toml`, where its answers are `"-"` for unsolved
+/// until they're filled in. An existing day is never touched, and nothing is
+/// written unless every file can be updated.
+pub fn new_day(root: &Path, day: u8) -> Result<NewDay> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day: {day}, expected 1 to 25").into());
+    }
+
+    let name = format!("aoc{day:02}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()).into());
+    }
+
+    let edits: [(PathBuf, Edit); 6] = [
+        (root.join("Cargo.toml"), |m, day| {
+            add_member(m, &format!("aoc{day:02}"))
+        }),
+        (root.join("aoc").join("Cargo.toml"), add_dependency),
+        (
+            root.join("aoc").join("src").join("registry.rs"),
+            add_to_registry,
+        ),
+        (
+            root.join("aoc").join("tests").join("answers.rs"),
+            add_answers_test,
+        ),
+        (
+            root.join("aoc").join("tests").join("fuzz.rs"),
+            add_fuzz_test,
+        ),
+        (root.join("answers.toml"), add_answer_tables),
+    ];
+    let mut updated = vec![];
+    for (path, edit) in edits {
+        let text = fs::read_to_string(&path).map_err(|e| format!("{}: {e}", path.display()))?;
+        let text = edit(&text, day).map_err(|e| format!("{}: {e}", path.display()))?;
+        updated.push((path, text));
+    }
+
+    let fill = |template: &str| template.replace("DD", &format!("{day:02}"));
+    let files = [
+        (dir.join("Cargo.toml"), fill(CARGO_TOML)),
+        (dir.join("src").join("main.rs"), fill(MAIN_RS)),
+        (dir.join("src").join("lib.rs"), fill(LIB_RS)),
+        (dir.join("input").join("sample.txt"), String::new()),
+        (dir.join("input").join("input.txt"), String::new()),
+    ];
+
+    for (path, contents) in files.iter().chain(&updated) {
+        let write = || {
+            fs::create_dir_all(path.parent().unwrap_or(root))?;
+            fs::write(path, contents)
+        };
+        write().map_err(|e| format!("{}: {e}", path.display()))?;
+    }
+
+    Ok(NewDay {
+        created: files.into_iter().map(|(path, _)| path).collect(),
+        updated: updated.into_iter().map(|(path, _)| path).collect(),
+    })
+}
+
+/// The workspace manifest with `name` among the members and the workspace
+/// dependencies, each kept in order.
+fn add_member(manifest: &str, name: &str) -> Result<String> {
+    let mut lines: Vec<String> = manifest.lines().map(String::from).collect();
+
+    let member = format!("    \"{name}\",");
+    let members = section(&lines, "members = [", |l| l.trim() == "]")
+        .ok_or("no workspace members in Cargo.toml")?;
+    insert_sorted(&mut lines, members, member);
+
+    let dependency = format!("{name} = {{ path = \"{name}\" }}");
+    if let Some(deps) = section(&lines, "[workspace.dependencies]", |l| l.trim().is_empty()) {
+        insert_sorted(&mut lines, deps, dependency);
+    }
+
+    let mut manifest = lines.join("\n");
+    manifest.push('\n');
+    Ok(manifest)
+}
+
+/// The lines after `start` up to the one matching `end`.
+fn section(
+    lines: &[String],
+    start: &str,
+    end: impl Fn(&str) -> bool,
+) -> Option<std::ops::Range<usize>> {
+    let first = lines.iter().position(|l| l.trim() == start)? + 1;
+    let len = lines[first..]
+        .iter()
+        .position(|l| end(l))
+        .unwrap_or(lines.len() - first);
+    Some(first..first + len)
+}
+
+fn insert_sorted(lines: &mut Vec<String>, within: std::ops::Range<usize>, line: String) {
+    let at = within
+        .clone()
+        .find(|&i| lines[i].trim() > line.trim())
+        .unwrap_or(within.end);
+    lines.insert(at, line);
+}
+
+/// Inserts `entry` ahead of the first day in `within` after `day`, and ahead
+/// of the attributes or comments that `lead` up to it, or else at the end.
+fn insert_day(
+    lines: &mut Vec<String>,
+    within: Range<usize>,
+    day: u8,
+    entry: Vec<String>,
+    day_of: impl Fn(&str) -> Option<u8>,
+    lead: impl Fn(&str) -> bool,
+) {
+    let mut at = within
+        .clone()
+        .find(|&i| day_of(lines[i].trim()).is_some_and(|d| d > day))
+        .unwrap_or(within.end);
+    if at < within.end {
+        while at > within.start && lead(lines[at - 1].trim()) {
+            at -= 1;
+        }
+    }
+    lines.splice(at..at, entry);
+}
+
+/// The day in `line` after `prefix`, as in `day07_input` or `number: 7,`.
+fn day_after(line: &str, prefix: &str) -> Option<u8> {
+    let rest = line.strip_prefix(prefix)?;
+    let digits: String = rest.chars().take_while(char::is_ascii_digit).collect();
+    digits.parse().ok()
+}
+
+fn join(lines: Vec<String>) -> String {
+    let mut text = lines.join("\n");
+    text.push('\n');
+    text
+}
+
+fn lines_of(text: &str) -> Vec<String> {
+    text.lines().map(String::from).collect()
+}
+
+/// `aoc`'s own dependency on the day.
+fn add_dependency(manifest: &str, day: u8) -> Result<String> {
+    let mut lines = lines_of(manifest);
+    let deps =
+        section(&lines, "[dependencies]", |l| l.trim().is_empty()).ok_or("no [dependencies]")?;
+    insert_sorted(&mut lines, deps, format!("aoc{day:02}.workspace = true"));
+    Ok(join(lines))
+}
+
+/// The day's entry in `registry::DAYS`.
+fn add_to_registry(registry: &str, day: u8) -> Result<String> {
+    let mut lines = lines_of(registry);
+    let days = section(&lines, "pub const DAYS: &[Day] = &[", |l| l.trim() == "];")
+        .ok_or("no DAYS list")?;
+    let entry = vec![
+        "    Day {".to_string(),
+        format!("        number: {day},"),
+        format!("        parse: parse::<aoc{day:02}::Day{day:02}>,"),
+        "    },".to_string(),
+    ];
+    let day_of = |l: &str| day_after(l, "number: ");
+    insert_day(&mut lines, days, day, entry, day_of, |l| l == "Day {");
+    Ok(join(lines))
+}
+
+/// Checks of the day's sample and input against `answers.toml`.
+fn add_answers_test(tests: &str, day: u8) -> Result<String> {
+    let mut lines = lines_of(tests);
+    let checks = section(&lines, "answers! {", |l| l.trim() == "}").ok_or("no answers! block")?;
+    let entry = vec![
+        format!("    day{day:02}_sample: {day}, \"sample\";"),
+        format!("    day{day:02}_input: {day}, \"input\";"),
+    ];
+    let day_of = |l: &str| day_after(l, "day");
+    insert_day(&mut lines, checks, day, entry, day_of, |l| {
+        l.starts_with("#[")
+    });
+    Ok(join(lines))
+}
+
+fn add_fuzz_test(tests: &str, day: u8) -> Result<String> {
+    let mut lines = lines_of(tests);
+    let days = section(&lines, "fuzz_days! {", |l| l.trim() == "}").ok_or("no fuzz_days! block")?;
+    insert_sorted(&mut lines, days, format!("    day{day:02}: {day};"));
+    Ok(join(lines))
+}
+
+/// Tables for the day's answers, unsolved until they're filled in from the
+/// puzzle.
+fn add_answer_tables(manifest: &str, day: u8) -> Result<String> {
+    let mut lines = lines_of(manifest);
+    let mut entry = vec![];
+    for input in ["sample", "input"] {
+        entry.extend([
+            format!("[day{day:02}.{input}]"),
+            "part1 = \"-\"".to_string(),
+            "part2 = \"-\"".to_string(),
+            String::new(),
+        ]);
+    }
+    let later = lines
+        .iter()
+        .any(|l| day_after(l.trim(), "[day").is_some_and(|d| d > day));
+    if !later {
+        // going on the end, so it needs a blank line before it rather than after
+        entry.rotate_right(1);
+        while lines.last().is_some_and(|l| l.is_empty()) {
+            lines.pop();
+        }
+    }
+
+    let all = 0..lines.len();
+    let day_of = |l: &str| day_after(l, "[day");
+    insert_day(&mut lines, all, day, entry, day_of, |l| l.starts_with('#'));
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+    Ok(join(lines))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(root: &Path, path: &str, text: &str) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, text).unwrap();
+    }
+
+    fn read(root: &Path, path: &str) -> String {
+        fs::read_to_string(root.join(path)).unwrap()
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        write(
+            &root,
+            "Cargo.toml",
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"aoc25\",\n]\n\n\
+             [workspace.dependencies]\naoc-common = { path = \"aoc-common\" }\n\n\
+             [profile.test]\nopt-level = 3\n",
+        );
+        write(
+            &root,
+            "aoc/Cargo.toml",
+            "[package]\nname = \"aoc\"\n\n[dependencies]\naoc-common.workspace = true\n\
+             aoc25.workspace = true\n",
+        );
+        write(
+            &root,
+            "aoc/src/registry.rs",
+            "pub const DAYS: &[Day] = &[\n    Day {\n        number: 1,\n        parse: parse::<aoc01::Day01>,\n    },\n\
+             \x20   Day {\n        number: 25,\n        parse: parse::<aoc25::Day25>,\n    },\n];\n",
+        );
+        write(
+            &root,
+            "aoc/tests/answers.rs",
+            "answers! {\n    day01_sample: 1, \"sample\";\n    #[ignore]\n    day25_input: 25, \"input\";\n}\n",
+        );
+        write(
+            &root,
+            "aoc/tests/fuzz.rs",
+            "fuzz_days! {\n    day01: 1;\n    day25: 25;\n}\n",
+        );
+        write(
+            &root,
+            "answers.toml",
+            "# answers\n\n[day01.sample]\npart1 = 1\n\n# the one puzzle\n[day25.sample]\npart1 = \"2=\"\n",
+        );
+
+        let new = new_day(&root, 3).unwrap();
+        assert_eq!((new.created.len(), new.updated.len()), (5, 6));
+        assert!(read(&root, "aoc03/src/lib.rs").contains("pub struct Day03 {"));
+        assert_eq!(
+            read(&root, "Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"aoc03\",\n    \"aoc25\",\n]\n\n\
+             [workspace.dependencies]\naoc-common = { path = \"aoc-common\" }\n\
+             aoc03 = { path = \"aoc03\" }\n\n[profile.test]\nopt-level = 3\n"
+        );
+        assert!(read(&root, "aoc/Cargo.toml").ends_with(
+            "aoc-common.workspace = true\naoc03.workspace = true\naoc25.workspace = true\n"
+        ));
+        assert!(read(&root, "aoc/src/registry.rs").contains(
+            "    },\n    Day {\n        number: 3,\n        parse: parse::<aoc03::Day03>,\n    },\n\
+             \x20   Day {\n        number: 25,"
+        ));
+        assert_eq!(
+            read(&root, "aoc/tests/answers.rs"),
+            "answers! {\n    day01_sample: 1, \"sample\";\n    day03_sample: 3, \"sample\";\n\
+             \x20   day03_input: 3, \"input\";\n    #[ignore]\n    day25_input: 25, \"input\";\n}\n"
+        );
+        assert!(read(&root, "aoc/tests/fuzz.rs").contains("day01: 1;\n    day03: 3;\n"));
+        assert_eq!(
+            read(&root, "answers.toml"),
+            "# answers\n\n[day01.sample]\npart1 = 1\n\n[day03.sample]\npart1 = \"-\"\n\
+             part2 = \"-\"\n\n[day03.input]\npart1 = \"-\"\npart2 = \"-\"\n\n# the one puzzle\n[day25.sample]\npart1 = \"2=\"\n"
+        );
+
+        // after the last day the tables go on the end
+        assert_eq!(
+            add_answer_tables("[day01.sample]\npart1 = 1\n\n", 2).unwrap(),
+            "[day01.sample]\npart1 = 1\n\n[day02.sample]\npart1 = \"-\"\npart2 = \"-\"\n\n\
+             [day02.input]\npart1 = \"-\"\npart2 = \"-\"\n"
+        );
+
+        let err = new_day(&root, 3).unwrap_err();
+        assert!(err.to_string().ends_with("aoc03 already exists"));
+        assert!(new_day(&root, 26).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,233 @@
+//! Talking to the Advent of Code website: `aoc fetch` downloads puzzle inputs,
+//! and the client here also carries `aoc submit`'s answers.
+//!
+//! Requests go through `curl`, which brings TLS along without adding a
+//! dependency. The session token is passed to it on stdin rather than on the
+//! command line, where other users could see it.
+//!
+//! The token comes from `AOC_SESSION`, or the file `aoc/session` in the config
+//! directory (`$XDG_CONFIG_HOME`, or `~/.config`). `AOC_BASE_URL` points
+//! requests somewhere other than the 2022 event, such as a local test server,
+//! and `AOC_USER_AGENT` replaces the default User-Agent, e.g. to add contact
+//! details.
+//!
+//! Requests are spaced at least five seconds apart. The time of the last one
+//! is kept in `aoc/last-request` beside the session file, so the limit holds
+//! across runs and not just within one.
+use std::{
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::Result;
+
+pub const BASE_URL: &str = "https://adventofcode.com/2022";
+
+const USER_AGENT: &str = concat!(
+    "aoc-2022/",
+    env!("CARGO_PKG_VERSION"),
+    " (personal solutions runner, via curl)"
+);
+
+/// The least time between two requests, to go easy on the site.
+const INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+    user_agent: String,
+    interval: Duration,
+    last_request: Option<Instant>,
+    stamp: Option<PathBuf>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            user_agent: USER_AGENT.to_string(),
+            interval: INTERVAL,
+            last_request: None,
+            stamp: None,
+        }
+    }
+
+    /// A client set up from the environment and config file.
+    pub fn from_env() -> Result<Self> {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| BASE_URL.to_string());
+        let mut client = Self::new(&base_url, &session()?);
+        if let Ok(user_agent) = env::var("AOC_USER_AGENT") {
+            client.user_agent = user_agent;
+        }
+        if let Some(dir) = config_dir() {
+            client = client.with_stamp(dir.join("aoc").join("last-request"));
+        }
+        Ok(client)
+    }
+
+    /// Waits at least `interval` between requests.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Keeps the time of the last request in `path`, so clients in other
+    /// processes wait for it too.
+    pub fn with_stamp(mut self, path: impl Into<PathBuf>) -> Self {
+        self.stamp = Some(path.into());
+        self
+    }
+
+    pub fn get(&mut self, path: &str) -> Result<Response> {
+        self.request(path, &[])
+    }
+
+    /// Posts `form` urlencoded.
+    pub fn post(&mut self, path: &str, form: &[(&str, &str)]) -> Result<Response> {
+        self.request(path, form)
+    }
+
+    fn request(&mut self, path: &str, form: &[(&str, &str)]) -> Result<Response> {
+        let stamped = self.stamp.as_deref().and_then(stamped);
+        let since = self.last_request.map(|last| last.elapsed()).into_iter();
+        if let Some(since) = since.chain(stamped).min() {
+            thread::sleep(self.interval.saturating_sub(since));
+        }
+        self.last_request = Some(Instant::now());
+        if let Some(path) = &self.stamp {
+            stamp(path)?;
+        }
+
+        let url = format!("{}{path}", self.base_url);
+        let mut config = format!(
+            "url = {}\nuser-agent = {}\ncookie = {}\n",
+            quote(&url),
+            quote(&self.user_agent),
+            quote(&format!("session={}", self.session)),
+        );
+        for (name, value) in form {
+            config += &format!("data-urlencode = {}\n", quote(&format!("{name}={value}")));
+        }
+
+        let mut curl = Command::new("curl")
+            .args(["--silent", "--show-error", "--config", "-"])
+            .args(["--write-out", "\\n%{http_code}"])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| format!("couldn't run curl: {e}"))?;
+        curl.stdin
+            .take()
+            .ok_or("curl has no stdin")?
+            .write_all(config.as_bytes())?;
+
+        let output = curl.wait_with_output()?;
+        if !output.status.success() {
+            let err = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("{url}: {}", err.trim()).into());
+        }
+
+        let stdout = String::from_utf8(output.stdout)?;
+        let (body, status) = stdout
+            .rsplit_once('\n')
+            .ok_or_else(|| format!("{url}: no status in curl's output"))?;
+        Ok(Response {
+            status: status
+                .parse()
+                .map_err(|_| format!("{url}: bad status {status:?}"))?,
+            body: body.to_string(),
+        })
+    }
+}
+
+/// A string in curl's config file syntax.
+fn quote(s: &str) -> String {
+    format!("\"{}\"", s.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// How long ago the request recorded in `path` was made. A time in the future,
+/// from the clock going back, counts as just now.
+fn stamped(path: &Path) -> Option<Duration> {
+    let millis = fs::read_to_string(path).ok()?.trim().parse().ok()?;
+    let then = UNIX_EPOCH + Duration::from_millis(millis);
+    Some(SystemTime::now().duration_since(then).unwrap_or_default())
+}
+
+/// Records a request made now in `path`, as milliseconds since the epoch.
+fn stamp(path: &Path) -> Result<()> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+    }
+    fs::write(path, format!("{now}\n")).map_err(|e| format!("{}: {e}", path.display()))?;
+    Ok(())
+}
+
+fn session() -> Result<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+
+    let path = config_dir()
+        .map(|dir| dir.join("aoc").join("session"))
+        .ok_or("no session token: set AOC_SESSION")?;
+    let session = fs::read_to_string(&path).map_err(|e| {
+        format!(
+            "no session token: set AOC_SESSION or save it to {} ({e})",
+            path.display()
+        )
+    })?;
+    Ok(session.trim().to_string())
+}
+
+fn config_dir() -> Option<PathBuf> {
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))
+}
+
+/// Whether an input has already been downloaded. An empty file, as `aoc new`
+/// leaves, doesn't count.
+pub fn is_cached(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|m| m.len() > 0)
+}
+
+/// Downloads a day's input to `path`, unless it is already there. Returns
+/// whether it was downloaded.
+pub fn fetch_input(client: &mut Client, day: u8, path: &Path) -> Result<bool> {
+    if is_cached(path) {
+        return Ok(false);
+    }
+
+    let response = client.get(&format!("/day/{day}/input"))?;
+    if response.status != 200 {
+        let reason = response.body.lines().next().unwrap_or_default();
+        return Err(format!("day {day}: HTTP {}: {reason}", response.status).into());
+    }
+    if response.body.trim().is_empty() {
+        return Err(format!("day {day}: the input was empty").into());
+    }
+
+    // write it whole or not at all, so a failure can't leave a cached half
+    let partial = path.with_extension("txt.part");
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&partial, &response.body).map_err(|e| format!("{}: {e}", partial.display()))?;
+    fs::rename(&partial, path).map_err(|e| format!("{}: {e}", path.display()))?;
+
+    Ok(true)
+}
@@ -0,0 +1,267 @@
+//! `aoc submit`: posting an answer to the website and keeping a log of every
+//! attempt.
+//!
+//! The log is what stops the same mistake being made twice: an answer the
+//! site has already called wrong is never sent again, and neither is one on
+//! the wrong side of a "too high" or "too low". It is a plain text file with a
+//! line per attempt: seconds since the epoch, day, part, outcome and answer,
+//! separated by tabs.
+use std::{
+    fmt, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::Result;
+
+use crate::site::Client;
+
+/// What the site made of an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// Sent too soon after a wrong answer; nothing was checked.
+    RateLimited,
+    /// The part was already solved, or isn't unlocked yet.
+    WrongLevel,
+    /// A page none of the above phrases were found in.
+    Unknown,
+}
+
+impl Outcome {
+    /// Reads the outcome from the page the site answers a submission with.
+    pub fn parse(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if page.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else if page.contains("You gave an answer too recently") {
+            Outcome::RateLimited
+        } else if page.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            Outcome::Unknown
+        }
+    }
+
+    /// Whether the site judged the answer incorrect.
+    pub fn is_wrong(self) -> bool {
+        matches!(self, Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow)
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::TooHigh => write!(f, "too-high"),
+            Outcome::TooLow => write!(f, "too-low"),
+            Outcome::RateLimited => write!(f, "rate-limited"),
+            Outcome::WrongLevel => write!(f, "wrong-level"),
+            Outcome::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl FromStr for Outcome {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Outcome::Correct),
+            "wrong" => Ok(Outcome::Wrong),
+            "too-high" => Ok(Outcome::TooHigh),
+            "too-low" => Ok(Outcome::TooLow),
+            "rate-limited" => Ok(Outcome::RateLimited),
+            "wrong-level" => Ok(Outcome::WrongLevel),
+            "unknown" => Ok(Outcome::Unknown),
+            _ => Err(format!("unknown outcome: {s}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// Seconds since the epoch.
+    pub time: u64,
+    pub day: u8,
+    pub part: u8,
+    pub outcome: Outcome,
+    pub answer: String,
+}
+
+#[derive(Debug, Default)]
+pub struct Log {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Log {
+    /// `submissions.log` at the workspace root.
+    pub fn default_path() -> PathBuf {
+        crate::workspace_dir().join("submissions.log")
+    }
+
+    /// Reads the log at `path`; one that doesn't exist yet is empty.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("{}: {e}", path.display()).into()),
+        };
+
+        let mut attempts = vec![];
+        for (idx, line) in text.lines().enumerate() {
+            let bad = || format!("{}:{}: malformed attempt", path.display(), idx + 1);
+            let fields: Vec<&str> = line.splitn(5, '\t').collect();
+            let [time, day, part, outcome, answer] = fields[..] else {
+                return Err(bad().into());
+            };
+            attempts.push(Attempt {
+                time: time.parse().map_err(|_| bad())?,
+                day: day.parse().map_err(|_| bad())?,
+                part: part.parse().map_err(|_| bad())?,
+                outcome: outcome.parse().map_err(|_| bad())?,
+                answer: answer.to_string(),
+            });
+        }
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Refuses an answer the log shows can't be right.
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Result<()> {
+        let number: Option<i64> = answer.parse().ok();
+
+        for attempt in self
+            .attempts
+            .iter()
+            .filter(|a| a.day == day && a.part == part)
+        {
+            let earlier: Option<i64> = attempt.answer.parse().ok();
+            let refused = match attempt.outcome {
+                Outcome::Correct => true,
+                outcome if outcome.is_wrong() && attempt.answer == answer => true,
+                Outcome::TooHigh => number.zip(earlier).is_some_and(|(n, e)| n >= e),
+                Outcome::TooLow => number.zip(earlier).is_some_and(|(n, e)| n <= e),
+                _ => false,
+            };
+
+            if refused {
+                return Err(format!(
+                    "day {day} part {part}: not submitting {answer}, {} was {}",
+                    attempt.answer, attempt.outcome
+                )
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Adds an attempt to the log, on disk as well.
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        let line = format!(
+            "{}\t{}\t{}\t{}\t{}\n",
+            attempt.time, attempt.day, attempt.part, attempt.outcome, attempt.answer
+        );
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|e| format!("{}: {e}", self.path.display()))?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+/// Checks `answer` against the log, posts it and logs what the site said.
+pub fn submit(
+    client: &mut Client,
+    log: &mut Log,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Outcome> {
+    if answer.is_empty() || answer == "-" || answer.contains('\n') {
+        return Err(format!("day {day} part {part}: {answer:?} can't be submitted").into());
+    }
+    log.check(day, part, answer)?;
+
+    let level = part.to_string();
+    let response = client.post(
+        &format!("/day/{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    if response.status != 200 {
+        return Err(format!("day {day} part {part}: HTTP {}", response.status).into());
+    }
+
+    let outcome = Outcome::parse(&response.body);
+    log.record(Attempt {
+        time: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs()),
+        day,
+        part,
+        outcome,
+        answer: answer.to_string(),
+    })?;
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        let attempt = |part, outcome, answer: &str| Attempt {
+            time: 0,
+            day: 1,
+            part,
+            outcome,
+            answer: answer.to_string(),
+        };
+        let log = Log {
+            path: PathBuf::new(),
+            attempts: vec![
+                attempt(1, Outcome::TooHigh, "500"),
+                attempt(1, Outcome::TooLow, "100"),
+                attempt(1, Outcome::Wrong, "321"),
+                attempt(1, Outcome::RateLimited, "300"),
+                attempt(2, Outcome::Correct, "7"),
+            ],
+        };
+
+        assert!(log.check(1, 1, "300").is_ok());
+        assert!(log.check(1, 1, "321").is_err());
+        assert!(log.check(1, 1, "500").is_err());
+        assert!(log.check(1, 1, "600").is_err());
+        assert!(log.check(1, 1, "50").is_err());
+        assert!(log.check(1, 2, "7").is_err());
+        assert!(log.check(2, 1, "500").is_ok());
+    }
+}
@@ -0,0 +1,143 @@
+//! Runs every day against the answers recorded in `answers.toml`, so a
+//! refactor that changes an answer fails here rather than on the website.
+use std::{fs, sync::OnceLock};
+
+use aoc::{answers::Answers, registry};
+use aoc_common::Answer;
+
+fn answers() -> &'static Answers {
+    static ANSWERS: OnceLock<Answers> = OnceLock::new();
+    ANSWERS.get_or_init(|| Answers::load().unwrap())
+}
+
+/// Checks the given parts (both when empty) of one day against one input.
+fn check(day: u8, input: &str, parts: &[u8]) {
+    let day = registry::find(day).unwrap();
+    let path = day.input_path(input);
+    let text = fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()));
+    let solution = (day.parse)(&text).unwrap();
+
+    let parts = if parts.is_empty() { &[1, 2] } else { parts };
+    let mut checked = 0;
+    for &part in parts {
+        let Some(expected) = answers().get(day.number, input, part) else {
+            continue;
+        };
+
+        let answer = match part {
+            1 => solution.part1(),
+            _ => solution.part2(),
+        };
+        assert!(
+            expected != "-" || answer == Answer::Unsolved,
+            "day {} part {part} on {input} is solved, so answers.toml needs its answer rather than \"-\"",
+            day.number
+        );
+        assert_eq!(
+            answer.to_string(),
+            expected,
+            "day {} part {part} on {input}",
+            day.number
+        );
+        checked += 1;
+    }
+
+    assert!(
+        checked > 0,
+        "no answers recorded for day {} on {input}",
+        day.number
+    );
+}
+
+macro_rules! answers {
+    ($($(#[$attr:meta])* $name:ident: $day:literal, $input:literal $(, part $part:literal)?;)*) => {
+        $(
+            #[test]
+            $(#[$attr])*
+            fn $name() {
+                check($day, $input, &[$($part)?]);
+            }
+        )*
+    };
+}
+
+answers! {
+    day01_sample: 1, "sample";
+    day01_input: 1, "input";
+    day02_sample: 2, "sample";
+    day02_input: 2, "input";
+    day03_sample: 3, "sample";
+    day03_input: 3, "input";
+    day04_sample: 4, "sample";
+    day04_input: 4, "input";
+    day05_sample: 5, "sample";
+    day05_input: 5, "input";
+    day06_sample: 6, "sample";
+    day06_input: 6, "input";
+    day07_sample: 7, "sample";
+    day07_input: 7, "input";
+    day08_sample: 8, "sample";
+    day08_input: 8, "input";
+    day09_sample: 9, "sample";
+    day09_sample2: 9, "sample2";
+    day09_input: 9, "input";
+    day10_sample: 10, "sample";
+    day10_sample2: 10, "sample2";
+    day10_input: 10, "input";
+    day11_sample: 11, "sample";
+    day11_input: 11, "input";
+    day12_sample: 12, "sample";
+    day12_input: 12, "input";
+    day13_sample: 13, "sample";
+    day13_input: 13, "input";
+    day14_sample: 14, "sample";
+    day14_input: 14, "input";
+    day15_sample: 15, "sample";
+    day15_input: 15, "input";
+    day16_sample: 16, "sample";
+    day16_input_part1: 16, "input", part 1;
+    #[ignore = "part 2 runs out of memory on the real input"]
+    day16_input_part2: 16, "input", part 2;
+    day17_sample: 17, "sample";
+    day17_input: 17, "input";
+    day18_sample: 18, "sample";
+    day18_input: 18, "input";
+    day19_sample_part1: 19, "sample", part 1;
+    #[ignore = "part 2 takes several minutes"]
+    day19_sample_part2: 19, "sample", part 2;
+    #[ignore = "takes over a minute"]
+    day19_input_part1: 19, "input", part 1;
+    #[ignore = "part 2 takes several minutes"]
+    day19_input_part2: 19, "input", part 2;
+    day20_sample: 20, "sample";
+    day20_input: 20, "input";
+    day21_sample: 21, "sample";
+    day21_input: 21, "input";
+    day22_sample: 22, "sample";
+    day22_input: 22, "input";
+    day23_sample: 23, "sample";
+    day23_sample2: 23, "sample2";
+    day23_input: 23, "input";
+    day24_sample: 24, "sample";
+    day24_sample2: 24, "sample2";
+    day24_input: 24, "input";
+    day25_sample: 25, "sample";
+    day25_input: 25, "input";
+}
+
+#[test]
+fn every_input_has_answers() {
+    for day in registry::DAYS {
+        let dir = day.input_path("input");
+        let dir = dir.parent().unwrap();
+        for entry in fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let name = path.file_stem().unwrap().to_str().unwrap();
+            assert!(
+                answers().inputs(day.number).contains(&name),
+                "{} has no answers in answers.toml",
+                path.display()
+            );
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! The days' public types, used directly rather than through `Solution`.
+use std::fs;
+
+use aoc::registry;
+use aoc07::Day07;
+use aoc13::Packet;
+use aoc19::Day19;
+use aoc24::Day24;
+use aoc25::Fuel;
+use aoc_common::Solution;
+
+fn read(day: u8, name: &str) -> String {
+    let path = registry::find(day).unwrap().input_path(name);
+    fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
+
+#[test]
+fn packets_order() {
+    let packet = |s: &str| s.parse::<Packet>().unwrap();
+    assert!(packet("[1,1,3,1,1]") < packet("[1,1,5,1,1]"));
+    assert!(packet("[[1],[2,3,4]]") < packet("[[1],4]"));
+    assert!(packet("[[[]]]") > packet("[[]]"));
+    assert!("[1,2".parse::<Packet>().is_err());
+}
+
+#[test]
+fn fuel_converts() {
+    let fuel: Fuel = "1=-0-2".parse().unwrap();
+    assert_eq!(fuel.to_decimal(), 1747);
+    assert_eq!(Fuel::from_decimal(1747), fuel);
+    assert_eq!(Fuel::from_decimal(2022).to_string(), "1=11-2");
+}
+
+#[test]
+fn directory_sizes() {
+    let day = Day07::parse(&read(7, "sample")).unwrap();
+    assert_eq!(day.sizes()[""], 48_381_165);
+}
+
+#[test]
+fn blueprint_geodes() {
+    let day = Day19::parse(&read(19, "sample")).unwrap();
+    let first = &day.blueprints()[0];
+    assert_eq!(first.id(), 1);
+    assert_eq!(first.max_geodes(24), 9);
+}
+
+#[test]
+fn maze_crossing() {
+    let day = Day24::parse(&read(24, "sample2")).unwrap();
+    let maze = day.maze();
+    assert_eq!(maze.crossing(maze.start(), maze.end(), 0), Some(18));
+    assert_eq!(maze.crossing(maze.end(), maze.start(), 18), Some(41));
+}
@@ -0,0 +1,84 @@
+//! Throws mangled puzzle inputs at every day's parser, and solves the ones
+//! that parse. Whatever goes in, the parser has to give back a solution or an
+//! error and the solution has to give back answers, if only `Unsolved`; a
+//! panic fails the test and names the input that caused it.
+use std::{cell::Cell, fs};
+
+use aoc::registry;
+use aoc_common::testing::fuzz;
+
+/// Inputs per day. Parsing is cheap, so this can be generous.
+const CASES: usize = 2000;
+
+/// How many of those inputs are solved too, when they parse. The parts can
+/// take a while on a mangled input, a huge step count say, so fewer.
+const SOLVED: usize = 200;
+
+/// Fuzzes one day, starting from its sample inputs, solving the first
+/// `solved` inputs as well as parsing them.
+fn check(day: u8, solved: usize) {
+    let day = registry::find(day).unwrap();
+    let dir = day.dir().join("input");
+    let mut seeds = vec![];
+    for entry in fs::read_dir(&dir).unwrap_or_else(|e| panic!("{}: {e}", dir.display())) {
+        let path = entry.unwrap().path();
+        let is_sample = path
+            .file_stem()
+            .is_some_and(|s| s.to_string_lossy().starts_with("sample"));
+        if is_sample {
+            seeds.push(fs::read_to_string(&path).unwrap());
+        }
+    }
+    seeds.sort();
+
+    let seeds: Vec<&str> = seeds.iter().map(String::as_str).collect();
+    let case = Cell::new(0);
+    fuzz(CASES, &seeds, |input| {
+        let parsed = (day.parse)(input);
+        if let (Ok(solution), true) = (parsed, case.get() < solved) {
+            solution.part1();
+            solution.part2();
+        }
+        case.set(case.get() + 1);
+    });
+}
+
+macro_rules! fuzz_days {
+    ($($(#[doc = $why:literal])* $name:ident: $day:literal $(, solved $solved:literal)?;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check($day, [$($solved,)? SOLVED][0]);
+            }
+        )*
+    };
+}
+
+fuzz_days! {
+    day01: 1;
+    day02: 2;
+    day03: 3;
+    day04: 4;
+    day05: 5;
+    day06: 6;
+    day07: 7;
+    day08: 8;
+    day09: 9;
+    day10: 10;
+    day11: 11;
+    day12: 12;
+    day13: 13;
+    day14: 14;
+    day15: 15;
+    day16: 16;
+    day17: 17;
+    day18: 18;
+    /// even the sample takes minutes to solve
+    day19: 19, solved 0;
+    day20: 20;
+    day21: 21;
+    day22: 22;
+    day23: 23;
+    day24: 24;
+    day25: 25;
+}
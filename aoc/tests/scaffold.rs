@@ -0,0 +1,118 @@
+//! Scaffolds a day with `aoc new` into a copy of the workspace and runs the
+//! suites there, so a new day starts out passing them rather than failing.
+//! The copy has no other days, which keeps it quick to build.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use aoc::{scaffold, workspace_dir};
+
+fn copy_dir(from: &Path, to: &Path) {
+    fs::create_dir_all(to).unwrap();
+    for entry in fs::read_dir(from).unwrap() {
+        let path = entry.unwrap().path();
+        let target = to.join(path.file_name().unwrap());
+        if path.is_dir() {
+            copy_dir(&path, &target);
+        } else {
+            fs::copy(&path, &target).unwrap();
+        }
+    }
+}
+
+/// `text` without the lines inside the block opened by the line `start`.
+fn empty_block(text: &str, start: &str, end: &str) -> String {
+    let mut inside = false;
+    let mut out = String::new();
+    for l in text.lines() {
+        if inside && l == end {
+            inside = false;
+        }
+        if !inside {
+            out.push_str(l);
+            out.push('\n');
+        }
+        if l == start {
+            inside = true;
+        }
+    }
+    out
+}
+
+/// A workspace with `aoc` and `aoc-common` and nothing for any day.
+fn workspace_without_days(root: &Path) {
+    let source = workspace_dir();
+    for dir in ["aoc", "aoc-common"] {
+        copy_dir(&source.join(dir), &root.join(dir));
+    }
+    fs::copy(source.join("Cargo.lock"), root.join("Cargo.lock")).unwrap();
+    fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nresolver = \"2\"\nmembers = [\n    \"aoc\",\n    \"aoc-common\",\n]\n\n\
+         [workspace.dependencies]\naoc-common = { path = \"aoc-common\" }\n",
+    )
+    .unwrap();
+    fs::write(root.join("answers.toml"), "# answers\n").unwrap();
+
+    let edit = |path: &str, edit: &dyn Fn(&str) -> String| {
+        let path = root.join(path);
+        let text = fs::read_to_string(&path).unwrap();
+        fs::write(path, edit(&text)).unwrap();
+    };
+    edit("aoc/Cargo.toml", &|t| {
+        let deps = t
+            .lines()
+            .filter(|l| !l.starts_with("aoc") || l.starts_with("aoc-"));
+        deps.map(|l| format!("{l}\n")).collect()
+    });
+    edit("aoc/src/registry.rs", &|t| {
+        empty_block(t, "pub const DAYS: &[Day] = &[", "];")
+    });
+    edit("aoc/tests/answers.rs", &|t| {
+        empty_block(t, "answers! {", "}")
+    });
+    edit("aoc/tests/fuzz.rs", &|t| {
+        empty_block(t, "fuzz_days! {", "}")
+    });
+    // only the suites a new day is added to
+    for test in ["api.rs", "scaffold.rs", "site.rs"] {
+        fs::remove_file(root.join("aoc/tests").join(test)).unwrap();
+    }
+}
+
+#[test]
+fn new_day_passes_the_suites() {
+    let root = env::temp_dir().join(format!("aoc-new-day-{}", std::process::id()));
+    workspace_without_days(&root);
+    scaffold::new_day(&root, 7).unwrap();
+
+    // a target directory of its own, kept between runs so only the copy's
+    // crates are rebuilt
+    let target: PathBuf = workspace_dir().join("target").join("new-day");
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let output = Command::new(cargo)
+        .args(["test", "-p", "aoc07", "-p", "aoc"])
+        .args(["--test", "answers", "--test", "fuzz", "--lib"])
+        .env("CARGO_TARGET_DIR", &target)
+        .current_dir(&root)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stdout}\n{stderr}");
+    for test in [
+        "day07_sample",
+        "day07_input",
+        "day07 ",
+        "tests::test_sample",
+    ] {
+        assert!(
+            stdout.contains(&format!("test {test}")),
+            "{test} didn't run:\n{stdout}"
+        );
+    }
+
+    fs::remove_dir_all(&root).unwrap();
+}
@@ -0,0 +1,194 @@
+//! Fetching and submitting against a stub server on localhost, so nothing
+//! here needs the network or a real session.
+use std::{
+    fs,
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc::{
+    site::{self, Client},
+    submit::{self, Log, Outcome},
+};
+
+/// A request as the stub server saw it.
+#[derive(Debug)]
+struct Request {
+    line: String,
+    headers: Vec<String>,
+    body: String,
+}
+
+impl Request {
+    fn header(&self, name: &str) -> Option<&str> {
+        let prefix = format!("{}: ", name.to_lowercase());
+        self.headers
+            .iter()
+            .find(|h| h.to_lowercase().starts_with(&prefix))
+            .map(|h| &h[prefix.len()..])
+    }
+}
+
+/// Serves `responses` as (status, body) to one connection each, and hands
+/// back the requests it got.
+fn stub_server(responses: Vec<(u16, &'static str)>) -> (String, Receiver<Request>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/2022", listener.local_addr().unwrap());
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        for (status, body) in responses {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let mut headers = vec![];
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                headers.push(header.trim().to_string());
+            }
+
+            let mut request = Request {
+                line: line.trim().to_string(),
+                headers,
+                body: String::new(),
+            };
+            let length = request
+                .header("content-length")
+                .map_or(0, |l| l.parse().unwrap());
+            let mut sent = vec![0; length];
+            reader.read_exact(&mut sent).unwrap();
+            request.body = String::from_utf8(sent).unwrap();
+
+            let mut stream = reader.into_inner();
+            write!(
+                stream,
+                "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            tx.send(request).unwrap();
+        }
+    });
+
+    (url, rx)
+}
+
+fn client(url: &str) -> Client {
+    Client::new(url, "s3cr3t").with_interval(Duration::ZERO)
+}
+
+#[test]
+fn fetch_downloads_once() {
+    let (url, requests) = stub_server(vec![(200, "1000\n2000\n")]);
+    let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+    let path = dir.join("input").join("input.txt");
+    let mut client = client(&url);
+
+    assert!(site::fetch_input(&mut client, 1, &path).unwrap());
+    assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+
+    let request = requests.recv().unwrap();
+    assert_eq!(request.line, "GET /2022/day/1/input HTTP/1.1");
+    assert_eq!(request.header("cookie"), Some("session=s3cr3t"));
+    assert!(request
+        .header("user-agent")
+        .unwrap()
+        .starts_with("aoc-2022/"));
+
+    // cached, so the server, which has no more responses, isn't asked again
+    assert!(!site::fetch_input(&mut client, 1, &path).unwrap());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn fetch_reports_errors() {
+    let (url, _requests) = stub_server(vec![(
+        400,
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+    )]);
+    let path = std::env::temp_dir().join(format!("aoc-fetch-error-{}.txt", std::process::id()));
+
+    let err = site::fetch_input(&mut client(&url), 2, &path).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day 2: HTTP 400: Puzzle inputs differ by user.  Please log in to get your puzzle input."
+    );
+    assert!(!path.exists());
+}
+
+#[test]
+fn rate_limit_holds_across_clients() {
+    let (url, requests) = stub_server(vec![(200, "a\n"), (200, "b\n")]);
+    let stamp = std::env::temp_dir().join(format!("aoc-stamp-{}", std::process::id()));
+    let _ = fs::remove_file(&stamp);
+    let interval = Duration::from_millis(400);
+    let start = Instant::now();
+
+    // a second client, as a second run would have, still waits out the first
+    for _ in 0..2 {
+        let mut client = Client::new(&url, "s3cr3t")
+            .with_interval(interval)
+            .with_stamp(&stamp);
+        assert_eq!(client.get("/day/1/input").unwrap().status, 200);
+        requests.recv().unwrap();
+    }
+    // the stamp is in whole milliseconds, so allow for the one it drops
+    assert!(start.elapsed() >= interval - Duration::from_millis(1));
+    assert!(fs::read_to_string(&stamp)
+        .unwrap()
+        .trim()
+        .parse::<u128>()
+        .is_ok());
+
+    fs::remove_file(&stamp).unwrap();
+}
+
+const TOO_HIGH: &str = "<main><article><p>That's not the right answer; \
+your answer is too high.  Please wait one minute before trying again.</p></article></main>";
+const CORRECT: &str = "<main><article><p>That's the right answer!  You are one gold star \
+closer to collecting enough star fruit.</p></article></main>";
+
+#[test]
+fn submit_logs_attempts() {
+    let (url, requests) = stub_server(vec![(200, TOO_HIGH), (200, CORRECT)]);
+    let path = std::env::temp_dir().join(format!("aoc-submit-{}.log", std::process::id()));
+    let _ = fs::remove_file(&path);
+    let mut client = client(&url);
+    let mut log = Log::load(&path).unwrap();
+
+    let outcome = submit::submit(&mut client, &mut log, 4, 2, "900").unwrap();
+    assert_eq!(outcome, Outcome::TooHigh);
+    let request = requests.recv().unwrap();
+    assert_eq!(request.line, "POST /2022/day/4/answer HTTP/1.1");
+    assert_eq!(request.body, "level=2&answer=900");
+
+    // refused from the log without asking the server
+    let err = submit::submit(&mut client, &mut log, 4, 2, "901").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "day 4 part 2: not submitting 901, 900 was too-high"
+    );
+
+    let outcome = submit::submit(&mut client, &mut log, 4, 2, "841").unwrap();
+    assert_eq!(outcome, Outcome::Correct);
+    assert_eq!(requests.recv().unwrap().body, "level=2&answer=841");
+
+    let log = Log::load(&path).unwrap();
+    let seen: Vec<_> = log
+        .attempts()
+        .iter()
+        .map(|a| (a.answer.as_str(), a.outcome))
+        .collect();
+    assert_eq!(seen, [("900", Outcome::TooHigh), ("841", Outcome::Correct)]);
+    fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,366 @@
+//! Day 1: Calorie Counting. An [`Inventory`] of the snacks each [`Elf`]
+//! carries, and the elves carrying the most.
+//!
+//! For inputs too big to hold, [`stream_stats`] reads a line at a time and
+//! keeps just running totals and the [`TopN`] so far, finding the elves with
+//! the same [`Elves`] as an inventory. The binary's [`Options`] turn it on with
+//! `--stats [k]`.
+use aoc_common::{
+    parse_field, report::Table, Answer, Args, Flags, Input, ParseError, Result, Solution,
+};
+use std::{
+    cmp::Reverse, collections::BinaryHeap, io::BufRead, iter::Enumerate, path::Path, str::FromStr,
+};
+
+/// The calories of each snack one elf carries.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Elf {
+    pub snacks: Vec<u64>,
+}
+
+impl Elf {
+    /// The calories of all the snacks. An [`Inventory`] makes sure this fits.
+    pub fn total(&self) -> u64 {
+        self.snacks.iter().sum()
+    }
+}
+
+/// The elves in lines of an inventory: a calorie count per line, with a blank
+/// line between one elf and the next. Windows line endings, extra blank lines
+/// and a missing final newline are all fine, but an elf whose calories add up
+/// to more than a `u64` holds is an error, at its line. The lines can come
+/// from a string or a reader, with errors of their own.
+pub struct Elves<I> {
+    lines: Enumerate<I>,
+}
+
+impl<I> Elves<I> {
+    pub fn new(lines: I) -> Self
+    where
+        I: Iterator,
+    {
+        Self {
+            lines: lines.enumerate(),
+        }
+    }
+}
+
+impl<I, L, E> Iterator for Elves<I>
+where
+    I: Iterator<Item = std::result::Result<L, E>>,
+    L: AsRef<str>,
+    E: From<ParseError>,
+{
+    type Item = std::result::Result<Elf, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut elf = Elf::default();
+        let mut total: u64 = 0;
+        for (i, l) in &mut self.lines {
+            let l = match l {
+                Ok(l) => l,
+                Err(e) => return Some(Err(e)),
+            };
+            let l = l.as_ref().trim();
+            if l.is_empty() {
+                // several blank lines in a row still only end one elf
+                if elf.snacks.is_empty() {
+                    continue;
+                }
+                return Some(Ok(elf));
+            }
+
+            let error = |e: ParseError| Some(Err(e.at_line(i + 1).into()));
+            let calories = match parse_field(l, l, "a calorie count") {
+                Ok(calories) => calories,
+                Err(e) => return error(e),
+            };
+            total = match total.checked_add(calories) {
+                Some(total) => total,
+                None => return error(ParseError::new(l, l, "a total that fits in 64 bits")),
+            };
+            elf.snacks.push(calories);
+        }
+
+        (!elf.snacks.is_empty()).then_some(Ok(elf))
+    }
+}
+
+/// Every elf's snacks, in the order they were listed, as read by [`Elves`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Inventory {
+    elves: Vec<Elf>,
+}
+
+impl FromStr for Inventory {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        let elves: Vec<Elf> =
+            Elves::new(input.lines().map(Ok)).collect::<std::result::Result<_, _>>()?;
+        if elves.is_empty() {
+            return Err(ParseError::missing(input, "an elf's calorie counts"));
+        }
+
+        Ok(Self { elves })
+    }
+}
+
+impl Inventory {
+    pub fn elves(&self) -> &[Elf] {
+        &self.elves
+    }
+
+    /// The `k` largest calorie totals, largest first.
+    pub fn top_n(&self, k: usize) -> Vec<u64> {
+        let mut top = TopN::new(k);
+        for elf in &self.elves {
+            top.push(elf.total());
+        }
+        top.into_vec()
+    }
+}
+
+/// The `k` largest numbers pushed, in space for just `k`.
+#[derive(Debug, Clone)]
+pub struct TopN {
+    k: usize,
+    // the smallest of the best so far is on top, ready to be pushed out
+    heap: BinaryHeap<Reverse<u64>>,
+}
+
+impl TopN {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, n: u64) {
+        self.heap.push(Reverse(n));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// Largest first.
+    pub fn into_vec(self) -> Vec<u64> {
+        let mut top: Vec<u64> = self.heap.into_iter().map(|Reverse(n)| n).collect();
+        top.sort_by(|a, b| b.cmp(a));
+        top
+    }
+}
+
+/// Summary statistics of the elves' calorie totals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub mean: f64,
+    /// Only worked out when asked for, as it needs every elf's total.
+    pub median: Option<f64>,
+    pub max: u64,
+    /// The largest totals, largest first.
+    pub top: Vec<u64>,
+}
+
+/// Reads an inventory a line at a time, holding one elf's snacks, a few
+/// running totals and the top `k`, however big the input. With `median` every
+/// elf's total is kept as well, so memory grows with the number of elves.
+pub fn stream_stats(reader: impl BufRead, k: usize, median: bool) -> Result<Stats> {
+    let mut totals: Vec<u64> = vec![];
+    let mut top = TopN::new(k);
+    let (mut count, mut sum, mut max) = (0, 0u128, 0);
+    let lines = reader.lines().map(|l| -> Result<String> { Ok(l?) });
+    for elf in Elves::new(lines) {
+        let total = elf?.total();
+        if median {
+            totals.push(total);
+        }
+        top.push(total);
+        count += 1;
+        sum += total as u128;
+        max = max.max(total);
+    }
+
+    if count == 0 {
+        return Err(ParseError::missing("", "an elf's calorie counts").into());
+    }
+
+    let median = median.then(|| {
+        totals.sort_unstable();
+        match count % 2 {
+            1 => totals[count / 2] as f64,
+            _ => (totals[count / 2 - 1] as f64 + totals[count / 2] as f64) / 2.0,
+        }
+    });
+
+    Ok(Stats {
+        count,
+        mean: sum as f64 / count as f64,
+        median,
+        max,
+        top: top.into_vec(),
+    })
+}
+
+pub struct Day01 {
+    inventory: Inventory,
+}
+
+impl Day01 {
+    pub fn inventory(&self) -> &Inventory {
+        &self.inventory
+    }
+}
+
+impl Solution for Day01 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            inventory: input.parse()?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        self.inventory.top_n(1).iter().sum::<u64>().into()
+    }
+
+    fn part2(&self) -> Answer {
+        // three totals that each fit in a u64 might not fit together
+        let top = self.inventory.top_n(3);
+        top.iter().map(|&t| t as u128).sum::<u128>().into()
+    }
+}
+
+/// Day 1's own flags: `--stats [k]` prints the [`Stats`] of the input, with
+/// the top `k` totals (3 by default), rather than solving it, and `--median`
+/// adds the median to them.
+#[derive(Debug, Default)]
+pub struct Options {
+    stats: Option<usize>,
+    median: bool,
+}
+
+impl Flags for Options {
+    type Solution = Day01;
+
+    fn take(&mut self, arg: &str, args: &mut Args) -> Result<bool> {
+        match arg {
+            "--stats" => {
+                let k = args.next_if(|a| a.parse::<usize>().is_ok());
+                self.stats = Some(k.map_or(Ok(3), |k| k.parse())?);
+            }
+            "--median" => self.median = true,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn stream(&self, input: &Input, day_dir: &Path) -> Option<Result<Table>> {
+        let Some(k) = self.stats else {
+            return self
+                .median
+                .then(|| Err("--median goes with --stats".into()));
+        };
+        let stats = match input
+            .reader(day_dir)
+            .and_then(|reader| stream_stats(reader, k, self.median))
+        {
+            Ok(stats) => stats,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let mut columns = vec!["elves", "mean", "median", "max", "top"];
+        let mut row = vec![
+            stats.count.to_string(),
+            format!("{:.1}", stats.mean),
+            stats.median.map(|m| format!("{m:.1}")).unwrap_or_default(),
+            stats.max.to_string(),
+            stats
+                .top
+                .iter()
+                .map(u64::to_string)
+                .collect::<Vec<_>>()
+                .join(" "),
+        ];
+        if stats.median.is_none() {
+            columns.remove(2);
+            row.remove(2);
+        }
+
+        let mut table = Table::new(&columns);
+        table.push(row);
+        Some(Ok(table))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inventory() {
+        let inventory: Inventory = "1000\r\n2000\r\n\r\n\r\n4000\r\n\r\n5000\n6000"
+            .parse()
+            .unwrap();
+        let totals: Vec<u64> = inventory.elves().iter().map(Elf::total).collect();
+        assert_eq!(totals, [3000, 4000, 11000]);
+
+        assert_eq!(inventory.top_n(2), [11000, 4000]);
+        assert_eq!(inventory.top_n(5), [11000, 4000, 3000]);
+        assert!(inventory.top_n(0).is_empty());
+
+        let err = "100\n\nlots".parse::<Inventory>().unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (3, "lots"));
+        assert!("\n\n".parse::<Inventory>().is_err());
+
+        let huge = format!("{}\n1\n\n{}\n", u64::MAX, u64::MAX);
+        let err = huge.parse::<Inventory>().unwrap_err();
+        assert_eq!((err.line, err.text.as_str()), (2, "1"));
+        let inventory: Inventory = format!("{}\n\n{}\n\n7", u64::MAX, u64::MAX)
+            .parse()
+            .unwrap();
+        let day = Day01 { inventory };
+        assert_eq!(
+            day.part2().to_string(),
+            (2 * u64::MAX as u128 + 7).to_string()
+        );
+    }
+
+    #[test]
+    fn test_stream_stats() {
+        let input = "1000\n2000\n\n4000\r\n\r\n\n5000\n6000\n\n10000";
+        let stats = stream_stats(input.as_bytes(), 2, true).unwrap();
+        assert_eq!(
+            stats,
+            Stats {
+                count: 4,
+                mean: 7000.0,
+                median: Some(7000.0),
+                max: 11000,
+                top: vec![11000, 10000],
+            }
+        );
+        let bounded = stream_stats(input.as_bytes(), 2, false).unwrap();
+        assert_eq!(
+            bounded,
+            Stats {
+                median: None,
+                ..stats
+            }
+        );
+
+        // the same elves as an inventory, trailing blank lines and all
+        let input = "\r\n7\r\n\r\n8\r\n9\r\n\r\n\r\n";
+        let inventory: Inventory = input.parse().unwrap();
+        let stats = stream_stats(input.as_bytes(), 3, false).unwrap();
+        assert_eq!((stats.count, stats.top), (2, inventory.top_n(3)));
+
+        let err = stream_stats("1\n\n2\nx3\n".as_bytes(), 3, false).unwrap_err();
+        assert!(err.to_string().starts_with("line 4, column 1"), "{err}");
+
+        let huge = format!("{}\n{}\n", u64::MAX, 1);
+        let err = stream_stats(huge.as_bytes(), 3, false).unwrap_err();
+        assert!(err.to_string().starts_with("line 2"), "{err}");
+    }
+}
@@ -0,0 +1,633 @@
+//! Day 2: Rock Paper Scissors, played as any [`CyclicGame`]: an odd number of
+//! shapes where each one beats exactly half of the others. Rock Paper
+//! Scissors and Rock Paper Scissors Lizard Spock are built in, and other games
+//! can be read from rules written the same way as [`RPS`], which also give the
+//! guide's letters for outcomes.
+//!
+//! [`Day02::analyse`] doesn't trust the puzzle's reading of the second column
+//! and scores the guide under every way of mapping its letters.
+//!
+//! The binary's [`Options`] pick the game with `--game <rps|rpsls|path>` and
+//! print the analysis with `--analyse`.
+use aoc_common::{debug, report::Table, warn, Answer, Args, Flags, ParseError, Result, Solution};
+use std::{fs, str::FromStr};
+
+/// Rock Paper Scissors. Each line names a shape, the letters the opponent and
+/// we use for it in the strategy guide, and after the colon the shapes it
+/// beats. A shape scores its position in the list, from 1. The `outcomes` line
+/// gives the letters for losing, drawing and winning, X, Y and Z if it's left
+/// out.
+pub const RPS: &str = "\
+Rock     A X: Scissors
+Paper    B Y: Rock
+Scissors C Z: Paper
+outcomes: X Y Z
+";
+
+/// Rock Paper Scissors Lizard Spock.
+pub const RPSLS: &str = "\
+Rock     A V: Scissors Lizard
+Paper    B W: Rock Spock
+Scissors C X: Paper Lizard
+Spock    D Y: Rock Scissors
+Lizard   E Z: Paper Spock
+outcomes: X Y Z
+";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Lose, Outcome::Draw, Outcome::Win];
+
+    pub fn score(&self) -> u64 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+/// One of a game's shapes, and the letters the strategy guide uses for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Shape {
+    pub name: String,
+    pub theirs: String,
+    pub ours: String,
+}
+
+/// A game where every pair of different shapes has a winner, and every shape
+/// beats as many as it loses to. Shapes are referred to by their index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CyclicGame {
+    shapes: Vec<Shape>,
+    // beats[a][b] when shape a beats shape b
+    beats: Vec<Vec<bool>>,
+    // the guide's letters for losing, drawing and winning
+    outcomes: [String; 3],
+}
+
+impl CyclicGame {
+    pub fn rps() -> Self {
+        RPS.parse().expect("the built in rules are valid")
+    }
+
+    pub fn rpsls() -> Self {
+        RPSLS.parse().expect("the built in rules are valid")
+    }
+
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    pub fn beats(&self, a: usize, b: usize) -> bool {
+        self.beats[a][b]
+    }
+
+    /// The guide's letters for losing, drawing and winning.
+    pub fn outcome_letters(&self) -> &[String; 3] {
+        &self.outcomes
+    }
+
+    /// How a round goes for us.
+    pub fn outcome(&self, ours: usize, theirs: usize) -> Outcome {
+        if self.beats(ours, theirs) {
+            Outcome::Win
+        } else if self.beats(theirs, ours) {
+            Outcome::Lose
+        } else {
+            Outcome::Draw
+        }
+    }
+
+    /// Our score for a round: the shape's value plus the outcome's.
+    pub fn score(&self, ours: usize, theirs: usize) -> u64 {
+        ours as u64 + 1 + self.outcome(ours, theirs).score()
+    }
+
+    /// The shape to play against `theirs` for `outcome`. With more than three
+    /// shapes there are several that would do, so this picks the one worth
+    /// the most.
+    pub fn choose(&self, theirs: usize, outcome: Outcome) -> usize {
+        (0..self.shapes.len())
+            .rev()
+            .find(|&ours| self.outcome(ours, theirs) == outcome)
+            .expect("every game has a shape for every outcome")
+    }
+
+    /// A line of the strategy guide: the opponent's letter, a space and a
+    /// second letter, given back as the opponent's shape and that letter.
+    fn opponent<'a>(&self, l: &'a str) -> std::result::Result<(usize, &'a str), ParseError> {
+        let (theirs, second) = l
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(l, l, "two letters separated by a space"))?;
+        let theirs = self
+            .shapes
+            .iter()
+            .position(|s| s.theirs == theirs)
+            .ok_or_else(|| ParseError::new(l, theirs, "the opponent's shape"))?;
+        Ok((theirs, second))
+    }
+
+    /// Reads a line as the opponent's shape and ours.
+    pub fn round(&self, l: &str) -> std::result::Result<Round, ParseError> {
+        let (opponent, ours) = self.opponent(l)?;
+        let ours = self
+            .shapes
+            .iter()
+            .position(|s| s.ours == ours)
+            .ok_or_else(|| ParseError::new(l, ours, "our shape"))?;
+        Ok(Round { opponent, ours })
+    }
+
+    /// Reads a line as the opponent's shape and how the round should end.
+    pub fn round_two(&self, l: &str) -> std::result::Result<RoundTwo, ParseError> {
+        let (opponent, outcome) = self.opponent(l)?;
+        let outcome = self
+            .outcomes
+            .iter()
+            .position(|o| o == outcome)
+            .ok_or_else(|| {
+                let expected = format!("an outcome, one of {}", self.outcomes.join(" "));
+                ParseError::new(l, outcome, expected)
+            })?;
+        Ok(RoundTwo {
+            opponent,
+            outcome: Outcome::ALL[outcome],
+        })
+    }
+}
+
+impl FromStr for CyclicGame {
+    type Err = ParseError;
+
+    /// Rules as in [`RPS`]. Blank lines and lines starting with `#` are
+    /// skipped.
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        let mut shapes = vec![];
+        let mut beaten = vec![];
+        let mut outcomes = ["X", "Y", "Z"].map(String::from);
+        for (i, l) in input.lines().enumerate() {
+            let l = l.trim();
+            if l.is_empty() || l.starts_with('#') {
+                continue;
+            }
+
+            let error = |found, expected| ParseError::new(l, found, expected).at_line(i + 1);
+            let (shape, beats) = l
+                .split_once(':')
+                .ok_or_else(|| error(l, "a shape, its letters and a colon"))?;
+            if shape.trim() == "outcomes" {
+                let letters: Vec<&str> = beats.split_whitespace().collect();
+                let [lose, draw, win] = letters[..] else {
+                    return Err(error(beats, "three letters, to lose, draw and win"));
+                };
+                if lose == draw || lose == win || draw == win {
+                    return Err(error(beats, "three different letters"));
+                }
+                outcomes = [lose, draw, win].map(String::from);
+                continue;
+            }
+            let fields: Vec<&str> = shape.split_whitespace().collect();
+            let [name, theirs, ours] = fields[..] else {
+                return Err(error(shape, "a name and two letters"));
+            };
+
+            let clash = shapes
+                .iter()
+                .find(|s: &&Shape| s.name == name || s.theirs == theirs || s.ours == ours);
+            if let Some(s) = clash {
+                let expected = format!("a shape with a different name and letters to {}", s.name);
+                return Err(error(shape, &expected));
+            }
+
+            shapes.push(Shape {
+                name: name.to_string(),
+                theirs: theirs.to_string(),
+                ours: ours.to_string(),
+            });
+            beaten.push((i + 1, l, beats.split_whitespace().collect::<Vec<_>>()));
+        }
+
+        let n = shapes.len();
+        if n < 3 || n % 2 == 0 {
+            let expected = format!("an odd number of shapes, at least 3, not {n}");
+            return Err(ParseError::missing(input, expected));
+        }
+
+        let mut beats = vec![vec![false; n]; n];
+        for (a, (line, l, names)) in beaten.iter().enumerate() {
+            for name in names {
+                let b = shapes
+                    .iter()
+                    .position(|s| s.name == *name)
+                    .filter(|&b| b != a)
+                    .ok_or_else(|| ParseError::new(l, name, "another shape").at_line(*line))?;
+                beats[a][b] = true;
+            }
+        }
+
+        for (a, (line, l, _)) in beaten.iter().enumerate() {
+            let error = |expected: String| ParseError::new(l, l, expected).at_line(*line);
+            if beats[a].iter().filter(|&&b| b).count() != n / 2 {
+                return Err(error(format!("a shape that beats {} others", n / 2)));
+            }
+            for b in (0..n).filter(|&b| b != a) {
+                if beats[a][b] == beats[b][a] {
+                    let other = &shapes[b].name;
+                    return Err(error(format!("either it or {other} to win, not both")));
+                }
+            }
+        }
+
+        Ok(Self {
+            shapes,
+            beats,
+            outcomes,
+        })
+    }
+}
+
+/// A line of the strategy guide read as the shapes both sides play.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub opponent: usize,
+    pub ours: usize,
+}
+
+/// A line of the strategy guide read as the opponent's shape and the outcome
+/// we're after.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoundTwo {
+    pub opponent: usize,
+    pub outcome: Outcome,
+}
+
+/// What the guide's second column letters stand for, in the order of the
+/// game's shapes' `ours` letters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Mapping {
+    Shapes(Vec<usize>),
+    Outcomes(Vec<Outcome>),
+}
+
+/// How the guide plays out under one [`Mapping`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interpretation {
+    pub mapping: Mapping,
+    pub total: u64,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+/// Every reading of the guide, shapes first and then outcomes, each in order
+/// of its letters' permutations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub interpretations: Vec<Interpretation>,
+}
+
+impl Analysis {
+    /// The highest total, the first of any tied.
+    pub fn best(&self) -> &Interpretation {
+        let best = self.interpretations.iter().map(|i| i.total).max();
+        self.first_with(best)
+    }
+
+    pub fn worst(&self) -> &Interpretation {
+        let worst = self.interpretations.iter().map(|i| i.total).min();
+        self.first_with(worst)
+    }
+
+    fn first_with(&self, total: Option<u64>) -> &Interpretation {
+        self.interpretations
+            .iter()
+            .find(|i| Some(i.total) == total)
+            .expect("there is always a reading by shapes")
+    }
+}
+
+/// Every ordering of `0..n`, in lexicographic order.
+fn permutations(n: usize) -> Vec<Vec<usize>> {
+    if n == 0 {
+        return vec![vec![]];
+    }
+    let mut all = vec![];
+    for first in 0..n {
+        for rest in permutations(n - 1) {
+            let mut p = vec![first];
+            p.extend(rest.into_iter().map(|i| if i >= first { i + 1 } else { i }));
+            all.push(p);
+        }
+    }
+    all
+}
+
+/// A reading of the whole guide, or the first line it doesn't fit.
+type Reading<T> = std::result::Result<Vec<T>, ParseError>;
+
+pub struct Day02 {
+    game: CyclicGame,
+    rounds: Reading<Round>,
+    rounds_two: Reading<RoundTwo>,
+}
+
+impl Day02 {
+    /// Reads the strategy guide for `game` rather than Rock Paper Scissors.
+    /// Every line has to make sense as shapes or as an outcome. A guide that
+    /// only makes sense one way, such as one using all five of Rock Paper
+    /// Scissors Lizard Spock's letters, still answers that way's part, with a
+    /// warning for the other.
+    pub fn with_game(input: &str, game: CyclicGame) -> Result<Self> {
+        let mut rounds = Ok(vec![]);
+        let mut rounds_two = Ok(vec![]);
+        for (i, l) in input.lines().enumerate() {
+            let (round, round_two) = (game.round(l), game.round_two(l));
+            if let (Err(e), Err(_)) = (&round, &round_two) {
+                return Err(e.clone().at_line(i + 1).into());
+            }
+            read(&mut rounds, round, i + 1);
+            read(&mut rounds_two, round_two, i + 1);
+        }
+
+        for (part, e) in [(1, rounds.as_ref().err()), (2, rounds_two.as_ref().err())] {
+            if let Some(e) = e {
+                warn!("the guide can't be read for part {part}, so it has no answer: {e}");
+            }
+        }
+
+        Ok(Self {
+            game,
+            rounds,
+            rounds_two,
+        })
+    }
+
+    pub fn game(&self) -> &CyclicGame {
+        &self.game
+    }
+
+    /// Scores the guide with its second column read as each possible
+    /// assignment of the letters to shapes, and, when there are exactly three
+    /// letters, to outcomes. Fails if the guide uses letters the game doesn't.
+    pub fn analyse(&self) -> std::result::Result<Analysis, ParseError> {
+        let rounds = self.rounds.as_ref().map_err(Clone::clone)?;
+        let n = self.game.shapes.len();
+        let mut mappings: Vec<Mapping> = permutations(n).into_iter().map(Mapping::Shapes).collect();
+        if n == 3 {
+            mappings.extend(
+                permutations(3)
+                    .into_iter()
+                    .map(|p| Mapping::Outcomes(p.into_iter().map(|i| Outcome::ALL[i]).collect())),
+            );
+        }
+
+        let interpretations = mappings
+            .into_iter()
+            .map(|mapping| self.interpret(rounds, mapping))
+            .collect();
+        Ok(Analysis { interpretations })
+    }
+
+    fn interpret(&self, rounds: &[Round], mapping: Mapping) -> Interpretation {
+        let mut played = Interpretation {
+            mapping,
+            total: 0,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+        };
+
+        for r in rounds {
+            let ours = match &played.mapping {
+                Mapping::Shapes(shapes) => shapes[r.ours],
+                Mapping::Outcomes(outcomes) => self.game.choose(r.opponent, outcomes[r.ours]),
+            };
+            played.total += self.game.score(ours, r.opponent);
+            match self.game.outcome(ours, r.opponent) {
+                Outcome::Win => played.wins += 1,
+                Outcome::Draw => played.draws += 1,
+                Outcome::Lose => played.losses += 1,
+            }
+        }
+        played
+    }
+
+    /// The letters of a mapping and what they stand for, e.g. `X=Rock`.
+    pub fn describe(&self, mapping: &Mapping) -> String {
+        let shapes = &self.game.shapes;
+        let meanings: Vec<String> = match mapping {
+            Mapping::Shapes(m) => m.iter().map(|&s| shapes[s].name.clone()).collect(),
+            Mapping::Outcomes(m) => m.iter().map(|o| format!("{o:?}")).collect(),
+        };
+        shapes
+            .iter()
+            .zip(meanings)
+            .map(|(s, meaning)| format!("{}={meaning}", s.ours))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl Solution for Day02 {
+    fn parse(input: &str) -> Result<Self> {
+        Self::with_game(input, CyclicGame::rps())
+    }
+
+    fn part1(&self) -> Answer {
+        // with_game has already warned about a guide that can't be read
+        let Ok(rounds) = &self.rounds else {
+            return Answer::Unsolved;
+        };
+        let total: u64 = rounds
+            .iter()
+            .map(|r| self.game.score(r.ours, r.opponent))
+            .sum();
+
+        total.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let Ok(rounds) = &self.rounds_two else {
+            return Answer::Unsolved;
+        };
+        let total: u64 = rounds
+            .iter()
+            .map(|r| {
+                let ours = self.game.choose(r.opponent, r.outcome);
+                debug!(
+                    "{} for {:?}: {}",
+                    self.game.shapes[r.opponent].name, r.outcome, self.game.shapes[ours].name
+                );
+                self.game.score(ours, r.opponent)
+            })
+            .sum();
+
+        total.into()
+    }
+}
+
+/// Adds a line's reading to the rest, unless an earlier line has already
+/// failed to fit.
+fn read<T>(reading: &mut Reading<T>, round: std::result::Result<T, ParseError>, line: usize) {
+    if let Ok(rounds) = reading {
+        match round {
+            Ok(round) => rounds.push(round),
+            Err(e) => *reading = Err(e.at_line(line)),
+        }
+    }
+}
+
+/// Day 2's own flags: `--game <rps|rpsls|path>` plays the guide as another
+/// game, and `--analyse` prints its [`Analysis`] instead of the answers.
+#[derive(Debug, Default)]
+pub struct Options {
+    game: Option<CyclicGame>,
+    analyse: bool,
+}
+
+impl Flags for Options {
+    type Solution = Day02;
+
+    fn take(&mut self, arg: &str, args: &mut Args) -> Result<bool> {
+        match arg {
+            "--game" => {
+                let game = args
+                    .next()
+                    .ok_or("--game needs rps, rpsls or a rules file")?;
+                self.game = Some(match game.as_str() {
+                    "rps" => CyclicGame::rps(),
+                    "rpsls" => CyclicGame::rpsls(),
+                    path => fs::read_to_string(path)
+                        .map_err(|e| format!("{path}: {e}"))?
+                        .parse()
+                        .map_err(|e| format!("{path}: {e}"))?,
+                });
+            }
+            "--analyse" => self.analyse = true,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+
+    fn parse(&self, input: &str) -> Result<Day02> {
+        let game = self.game.clone().unwrap_or_else(CyclicGame::rps);
+        Day02::with_game(input, game)
+    }
+
+    /// A row per interpretation, the best and worst marked as such.
+    fn report(&self, day: &Day02) -> Option<Result<Table>> {
+        if !self.analyse {
+            return None;
+        }
+        let analysis = match day.analyse() {
+            Ok(analysis) => analysis,
+            Err(e) => return Some(Err(e.into())),
+        };
+
+        let (best, worst) = (analysis.best(), analysis.worst());
+        let columns = [
+            "mapping", "total", "won", "drew", "lost", "win_rate", "rank",
+        ];
+        let mut table = Table::new(&columns);
+        for i in &analysis.interpretations {
+            let rounds = (i.wins + i.draws + i.losses).max(1);
+            let rank = if std::ptr::eq(i, best) {
+                "best"
+            } else if std::ptr::eq(i, worst) {
+                "worst"
+            } else {
+                ""
+            };
+            table.push(vec![
+                day.describe(&i.mapping),
+                i.total.to_string(),
+                i.wins.to_string(),
+                i.draws.to_string(),
+                i.losses.to_string(),
+                format!("{:.1}%", 100.0 * i.wins as f64 / rounds as f64),
+                rank.to_string(),
+            ]);
+        }
+        Some(Ok(table))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cyclic_game() {
+        let game = CyclicGame::rpsls();
+        let [rock, paper, scissors, spock, lizard] = [0, 1, 2, 3, 4];
+        assert!(game.beats(spock, rock) && game.beats(lizard, spock));
+        assert_eq!(game.outcome(paper, scissors), Outcome::Lose);
+        assert_eq!(game.score(rock, lizard), 1 + 6);
+        // paper and spock both beat rock; spock is worth more
+        assert_eq!(game.choose(rock, Outcome::Win), spock);
+
+        let day = Day02::with_game("A Y\nE X\n", game.clone()).unwrap();
+        assert_eq!(day.part1(), Answer::from(4u64 + 6 + 3 + 6));
+        assert_eq!(day.part2(), Answer::from(1u64 + 3 + 4));
+        // both readings of an RPSLS guide: Scissors, Lizard and Spock against
+        // Rock, Spock and Lizard, or to lose, win and draw against them, which
+        // Lizard does every time
+        let day = Day02::with_game("A X\nD Z\nE Y\n", game.clone()).unwrap();
+        assert_eq!(day.part1(), Answer::from(3u64 + 11 + 4));
+        assert_eq!(day.part2(), Answer::from(5u64 + 11 + 8));
+        // V and W are shapes but not outcomes, so only part 1 can be read
+        let day = Day02::with_game("A V\nB W\nC Z\n", game.clone()).unwrap();
+        assert_eq!(day.part1(), Answer::from(1u64 + 3 + 2 + 3 + 5));
+        assert_eq!(day.part2(), Answer::Unsolved);
+        assert!(Day02::with_game("A Q\n", game).is_err());
+
+        // a rules file's own letters for outcomes, L here being one of them
+        // but not a shape
+        let game: CyclicGame = "R A Q: S\nP B W: R\nS C E: P\noutcomes: L D W\n"
+            .parse()
+            .unwrap();
+        assert_eq!(game.outcome_letters(), &["L", "D", "W"]);
+        let day = Day02::with_game("A L\nB W\n", game).unwrap();
+        assert_eq!(day.part1(), Answer::Unsolved);
+        assert_eq!(day.part2(), Answer::from(3u64 + 9));
+        assert!("R A Q: S\nP B W: R\nS C E: P\noutcomes: L L W\n"
+            .parse::<CyclicGame>()
+            .is_err());
+
+        let err = "Rock A X: Paper\nPaper B Y: Rock\nScissors C Z: Paper\n"
+            .parse::<CyclicGame>()
+            .unwrap_err();
+        assert_eq!(err.line, 1);
+        assert!("Rock A X: Scissors\nPaper B Y: Rock\n"
+            .parse::<CyclicGame>()
+            .is_err());
+    }
+
+    #[test]
+    fn test_analyse() {
+        let day = Day02::parse("A Y\nB X\nC Z\n").unwrap();
+        let analysis = day.analyse().unwrap();
+        assert_eq!(analysis.interpretations.len(), 12);
+        // the puzzle's two readings are among them
+        assert_eq!(analysis.interpretations[0].total, 15);
+        assert_eq!(analysis.interpretations[6].total, 12);
+        let best = analysis.best();
+        assert_eq!((best.total, best.wins), (24, 3));
+        assert_eq!(day.describe(&best.mapping), "X=Scissors Y=Paper Z=Rock");
+        assert_eq!(analysis.worst().losses, 3);
+
+        // a guide in letters the game doesn't use can't be analysed
+        let day = Day02::with_game("A X\n", CyclicGame::rpsls()).unwrap();
+        assert_eq!(day.analyse().unwrap().interpretations.len(), 120);
+        let rps: CyclicGame = "R A Q: S\nP B Y: R\nS C Z: P\n".parse().unwrap();
+        let day = Day02::with_game("A X\n", rps).unwrap();
+        assert!(day.analyse().is_err());
+    }
+}
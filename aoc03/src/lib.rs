@@ -0,0 +1,287 @@
+//! Day 3: Rucksack Reorganization. Each compartment and rucksack is a set of
+//! [`Items`], one bit per item, so finding what they share is a few
+//! instructions rather than a scan.
+//!
+//! The binary's [`Options`] take `--group <n>` for groups of other sizes.
+use aoc_common::{warn, Answer, Args, Flags, ParseError, Result, Solution};
+use std::{fmt, str::FromStr};
+
+/// An item's priority: a-z are 1 to 26 and A-Z 27 to 52.
+pub fn priority(item: char) -> Option<u32> {
+    match item {
+        'a'..='z' => Some(item as u32 - 'a' as u32 + 1),
+        'A'..='Z' => Some(item as u32 - 'A' as u32 + 27),
+        _ => None,
+    }
+}
+
+/// The item with a priority, the inverse of [`priority`].
+pub fn item(priority: u32) -> Option<char> {
+    match priority {
+        1..=26 => char::from_u32('a' as u32 + priority - 1),
+        27..=52 => char::from_u32('A' as u32 + priority - 27),
+        _ => None,
+    }
+}
+
+/// A set of item types, bit `p` standing for the item with priority `p`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Items(pub u64);
+
+impl Items {
+    pub const EMPTY: Items = Items(0);
+
+    pub fn intersection(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    pub fn union(self, other: Items) -> Items {
+        Items(self.0 | other.0)
+    }
+
+    pub fn difference(self, other: Items) -> Items {
+        Items(self.0 & !other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn contains(self, item: char) -> bool {
+        priority(item).is_some_and(|p| self.0 & 1 << p != 0)
+    }
+
+    pub fn insert(&mut self, item: char) {
+        if let Some(p) = priority(item) {
+            self.0 |= 1 << p;
+        }
+    }
+
+    /// The priorities of the items, lowest first.
+    pub fn priorities(self) -> impl Iterator<Item = u32> {
+        (1..=52).filter(move |p| self.0 & 1 << p != 0)
+    }
+
+    /// The items, in order of priority.
+    pub fn items(self) -> impl Iterator<Item = char> {
+        self.priorities().filter_map(item)
+    }
+
+    pub fn priority_sum(self) -> u32 {
+        self.priorities().sum()
+    }
+}
+
+impl FromStr for Items {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let mut items = Items::EMPTY;
+        for (pos, c) in s.char_indices() {
+            if priority(c).is_none() {
+                let found = &s[pos..pos + c.len_utf8()];
+                return Err(ParseError::new(s, found, "an item, a-z or A-Z"));
+            }
+            items.insert(c);
+        }
+        Ok(items)
+    }
+}
+
+/// The items in order, e.g. `pL`.
+impl fmt::Display for Items {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.items().try_for_each(|c| write!(f, "{c}"))
+    }
+}
+
+/// A rucksack's two compartments, each half of its line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    pub left: Items,
+    pub right: Items,
+}
+
+impl Rucksack {
+    pub fn all(&self) -> Items {
+        self.left.union(self.right)
+    }
+
+    /// The items packed in both compartments. There should be just the one.
+    pub fn shared(&self) -> Items {
+        self.left.intersection(self.right)
+    }
+}
+
+impl FromStr for Rucksack {
+    type Err = ParseError;
+
+    fn from_str(l: &str) -> std::result::Result<Self, Self::Err> {
+        // checks every item, so a bad one is reported where it is in the line
+        l.parse::<Items>()?;
+        if !l.len().is_multiple_of(2) {
+            return Err(ParseError::new(l, l, "an even number of items"));
+        }
+
+        let (left, right) = l.split_at(l.len() / 2);
+        Ok(Self {
+            left: left.parse()?,
+            right: right.parse()?,
+        })
+    }
+}
+
+pub struct Day03 {
+    sacks: Vec<Rucksack>,
+    group_size: usize,
+}
+
+impl Day03 {
+    /// Reads the rucksacks for elves in groups of `group_size` rather than
+    /// three.
+    pub fn with_group_size(input: &str, group_size: usize) -> Result<Self> {
+        if group_size == 0 {
+            return Err("groups need at least one elf".into());
+        }
+
+        let mut sacks = vec![];
+        for (i, l) in input.lines().enumerate() {
+            sacks.push(l.parse::<Rucksack>().map_err(|e| e.at_line(i + 1))?);
+        }
+
+        if sacks.len() % group_size != 0 {
+            let expected = format!("rucksacks in groups of {group_size}");
+            return Err(ParseError::missing(input, expected).into());
+        }
+
+        Ok(Self { sacks, group_size })
+    }
+
+    pub fn sacks(&self) -> &[Rucksack] {
+        &self.sacks
+    }
+
+    /// The items every rucksack in each group carries. There should be just
+    /// the one, the group's badge.
+    pub fn badges(&self) -> Vec<Items> {
+        self.sacks
+            .chunks(self.group_size)
+            .map(|group| {
+                group
+                    .iter()
+                    .fold(Items(!0), |common, sack| common.intersection(sack.all()))
+            })
+            .collect()
+    }
+
+    /// The rucksacks and groups that don't share exactly one item, which the
+    /// answers count every shared item of.
+    pub fn oddities(&self) -> Vec<String> {
+        let sacks = self.sacks.iter().map(Rucksack::shared);
+        let sacks = sacks
+            .enumerate()
+            .map(|(i, s)| ("rucksack", i, s, "items shared"));
+        let groups = self.badges().into_iter().enumerate();
+        let groups = groups.map(|(i, b)| ("group", i, b, "possible badges"));
+        sacks
+            .chain(groups)
+            .filter(|(_, _, items, _)| items.len() != 1)
+            .map(|(what, i, items, shared)| {
+                format!("{what} {}: {} {shared}: {items}", i + 1, items.len())
+            })
+            .collect()
+    }
+}
+
+impl Solution for Day03 {
+    fn parse(input: &str) -> Result<Self> {
+        Self::with_group_size(input, 3)
+    }
+
+    fn part1(&self) -> Answer {
+        let total: u32 = self.sacks.iter().map(|s| s.shared().priority_sum()).sum();
+        total.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let total: u32 = self.badges().into_iter().map(Items::priority_sum).sum();
+        total.into()
+    }
+}
+
+/// Day 3's own flags: `--group <n>` looks for badges in groups of `n` elves.
+#[derive(Debug, Default)]
+pub struct Options {
+    group_size: Option<usize>,
+}
+
+impl Flags for Options {
+    type Solution = Day03;
+
+    fn take(&mut self, arg: &str, args: &mut Args) -> Result<bool> {
+        if arg != "--group" {
+            return Ok(false);
+        }
+        let size = args.next().ok_or("--group needs a number of elves")?;
+        let size = size
+            .parse()
+            .map_err(|_| format!("--group needs a number of elves, not {size:?}"))?;
+        self.group_size = Some(size);
+        Ok(true)
+    }
+
+    /// Also warns of any [`oddities`](Day03::oddities), as with more elves
+    /// to a group there can easily be more than one possible badge.
+    fn parse(&self, input: &str) -> Result<Day03> {
+        let day = Day03::with_group_size(input, self.group_size.unwrap_or(3))?;
+        for oddity in day.oddities() {
+            warn!("{oddity}");
+        }
+        Ok(day)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_items() {
+        let a: Items = "vJrwpWtwJgWr".parse().unwrap();
+        let b: Items = "hcsFMMfFFhFp".parse().unwrap();
+        assert_eq!(a.intersection(b).to_string(), "p");
+        assert_eq!(a.union(b).len(), 14);
+        assert_eq!(a.difference(b).to_string(), "grtvwJW");
+        assert!(a.contains('W') && !a.contains('b'));
+        assert_eq!(
+            (priority('p'), priority('L'), item(38)),
+            (Some(16), Some(38), Some('L'))
+        );
+
+        let sack: Rucksack = "abcaBC".parse().unwrap();
+        assert_eq!(sack.shared().to_string(), "a");
+        let sack: Rucksack = "abXabY".parse().unwrap();
+        assert_eq!(sack.shared().to_string(), "ab");
+
+        let err = "abcd1f".parse::<Rucksack>().unwrap_err();
+        assert_eq!((err.column, err.text.as_str()), (5, "1"));
+
+        let day = Day03::with_group_size("ab\nba\nDa\nab\n", 2).unwrap();
+        assert_eq!(day.badges(), [Items(1 << 1 | 1 << 2), Items(1 << 1)]);
+        assert_eq!(
+            day.oddities(),
+            [
+                "rucksack 1: 0 items shared: ",
+                "rucksack 2: 0 items shared: ",
+                "rucksack 3: 0 items shared: ",
+                "rucksack 4: 0 items shared: ",
+                "group 1: 2 possible badges: ab",
+            ]
+        );
+        assert!(Day03::with_group_size("ab\nab\n", 3).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use aoc_common::{parse_field, parse_lines, Answer, ParseError, Result, Solution};
+use std::str::FromStr;
+
+#[derive(Debug, Clone)]
+struct Range {
+    start: i32,
+    end: i32,
+}
+
+impl FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(item: &str) -> std::result::Result<Self, Self::Err> {
+        let (start, end) = item
+            .split_once('-')
+            .ok_or_else(|| ParseError::new(item, item, "a range like 2-4"))?;
+        Ok(Self {
+            start: parse_field(item, start, "a section number")?,
+            end: parse_field(item, end, "a section number")?,
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Pair(Range, Range);
+
+impl FromStr for Pair {
+    type Err = ParseError;
+
+    fn from_str(l: &str) -> std::result::Result<Self, Self::Err> {
+        let (first, second) = l
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(l, l, "two ranges separated by a comma"))?;
+        let range = |r| Range::from_str(r).map_err(|e| e.within(l, r));
+        Ok(Pair(range(first)?, range(second)?))
+    }
+}
+
+pub struct Day04 {
+    sections: Vec<Pair>,
+}
+
+impl Solution for Day04 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            sections: parse_lines(input)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        let fully_overlaps = self.sections.iter().fold(0, |mut acc, s| {
+            if s.0.start <= s.1.start && s.0.end >= s.1.end
+                || s.1.start <= s.0.start && s.1.end >= s.0.end
+            {
+                acc += 1;
+            }
+
+            acc
+        });
+
+        fully_overlaps.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let overlaps: usize = self.sections.iter().fold(0, |mut acc, s| {
+            if !(s.0.end < s.1.start || s.1.end < s.0.start) {
+                acc += 1;
+            }
+
+            acc
+        });
+
+        overlaps.into()
+    }
+}
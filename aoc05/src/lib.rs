@@ -0,0 +1,229 @@
+use aoc_common::{parse_field, Answer, ParseError, Result, Solution};
+use regex::Regex;
+use std::{fmt, str::FromStr, sync::OnceLock};
+
+#[derive(Debug, PartialEq, Eq)]
+struct Move {
+    from: usize,
+    to: usize,
+    amount: usize,
+}
+
+/*
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+ */
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(item: &str) -> std::result::Result<Self, Self::Err> {
+        static RE: OnceLock<Regex> = OnceLock::new();
+        let re = RE.get_or_init(|| Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap());
+        let caps = re
+            .captures(item)
+            .ok_or_else(|| ParseError::new(item, item, "move N from N to N"))?;
+
+        // println!("{:?}", caps);
+        Ok(Self {
+            from: parse_field(item, &caps[2], "a stack number")?,
+            to: parse_field(item, &caps[3], "a stack number")?,
+            amount: parse_field(item, &caps[1], "a crate count")?,
+        })
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {} from {} to {}", self.amount, self.from, self.to)
+    }
+}
+
+//     [D]
+// [N] [C]
+// [Z] [M] [P]
+//  1   2   3
+fn parse_stacks(drawing: &str) -> Vec<Vec<char>> {
+    let mut rows: Vec<&str> = drawing.lines().collect();
+    let labels = rows.pop().unwrap_or_default();
+    let mut stacks = vec![vec![]; labels.split_whitespace().count()];
+
+    for row in rows.iter().rev() {
+        let row: Vec<char> = row.chars().collect();
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            if let Some(c) = row.get(1 + i * 4) {
+                if c.is_alphabetic() {
+                    stack.push(*c);
+                }
+            }
+        }
+    }
+
+    stacks
+}
+
+/// The crate on top of each stack, or `Unsolved` when one is empty.
+fn tops(stacks: &[Vec<char>]) -> Answer {
+    match stacks.iter().map(|s| s.last()).collect::<Option<String>>() {
+        Some(msg) => msg.into(),
+        None => Answer::Unsolved,
+    }
+}
+
+pub struct Day05 {
+    stacks: Vec<Vec<char>>,
+    moves: Vec<Move>,
+}
+
+impl Solution for Day05 {
+    fn parse(input: &str) -> Result<Self> {
+        let (drawing, moves) = input
+            .split_once("\n\n")
+            .ok_or("expected the crate drawing followed by a blank line")?;
+
+        let stacks = parse_stacks(drawing);
+
+        // both cranes move the same number of crates, so the heights can be
+        // followed here and a move off the bottom of a stack refused
+        let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+
+        // the moves start after the drawing and the blank line
+        let first_line = drawing.lines().count() + 2;
+        let mut parsed = vec![];
+        for (i, l) in moves.lines().enumerate() {
+            let m: Move = l
+                .parse()
+                .map_err(|e: ParseError| e.at_line(first_line + i))?;
+            if !(1..=stacks.len()).contains(&m.from) || !(1..=stacks.len()).contains(&m.to) {
+                let expected = format!("stacks numbered 1 to {}", stacks.len());
+                return Err(ParseError::new(l, l, expected)
+                    .at_line(first_line + i)
+                    .into());
+            }
+            if m.amount > heights[m.from - 1] {
+                let expected = format!(
+                    "at most {} crates, all that stack {} holds",
+                    heights[m.from - 1],
+                    m.from
+                );
+                return Err(ParseError::new(l, l, expected)
+                    .at_line(first_line + i)
+                    .into());
+            }
+            heights[m.from - 1] -= m.amount;
+            heights[m.to - 1] += m.amount;
+            parsed.push(m);
+        }
+
+        if let Some(i) = heights.iter().position(|&h| h == 0) {
+            let expected = format!("stack {} to end with a crate on top", i + 1);
+            return Err(ParseError::missing(input, expected).into());
+        }
+
+        Ok(Self {
+            stacks,
+            moves: parsed,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut stacks = self.stacks.clone();
+
+        for m in &self.moves {
+            // println!("{:?}", m);
+            let mut s = vec![];
+            for _ in 0..m.amount {
+                if let Some(c) = stacks[m.from - 1].pop() {
+                    s.push(c);
+                }
+            }
+
+            for c in s {
+                stacks[m.to - 1].push(c);
+            }
+        }
+
+        // for c in &stacks {
+        //     println!("{:?}", c);
+        // }
+
+        tops(&stacks)
+    }
+
+    fn part2(&self) -> Answer {
+        let mut stacks = self.stacks.clone();
+
+        for m in &self.moves {
+            let from = &mut stacks[m.from - 1];
+            let s: Vec<char> = from.drain(from.len().saturating_sub(m.amount)..).collect();
+
+            for c in s {
+                stacks[m.to - 1].push(c);
+            }
+        }
+
+        // for c in &stacks {
+        //     println!("{:?}", c);
+        // }
+
+        tops(&stacks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::testing::{check, fuzz};
+
+    #[test]
+    fn test_move() {
+        let number = |rng: &mut aoc_common::testing::Rng| rng.range(0..=1 << 40) as usize;
+        check(
+            500,
+            |rng| Move {
+                from: number(rng),
+                to: number(rng),
+                amount: number(rng),
+            },
+            |m| assert_eq!(m.to_string().parse::<Move>().unwrap(), *m),
+        );
+
+        fuzz(
+            2000,
+            &["move 1 from 2 to 1", "move 13 from 8 to 7"],
+            |item| {
+                let _ = item.parse::<Move>();
+            },
+        );
+    }
+
+    #[test]
+    fn test_heights() {
+        let drawing = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\n";
+        let day = Day05::parse(&format!("{drawing}move 2 from 2 to 1\n")).unwrap();
+        assert_eq!((day.part1(), day.part2()), ("CMP".into(), "DMP".into()));
+
+        // more crates than the stack holds, for either crane
+        let err = Day05::parse(&format!("{drawing}move 4 from 2 to 1\n"))
+            .err()
+            .unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 6, column 1: expected at most 3 crates, all that stack 2 holds, \
+             found \"move 4 from 2 to 1\""
+        );
+        assert!(Day05::parse("drawing\n\nmove 5 from 3 to 1").is_err());
+
+        // every crate taken off stack 3 leaves it without a top
+        let err = Day05::parse(&format!("{drawing}move 1 from 3 to 1\n"))
+            .err()
+            .unwrap();
+        assert!(err
+            .to_string()
+            .contains("stack 3 to end with a crate on top"));
+        let empty = vec![vec!['A'], vec![]];
+        assert_eq!(tops(&empty), Answer::Unsolved);
+    }
+}
@@ -0,0 +1,33 @@
+use aoc_common::{Answer, Result, Solution};
+use std::collections::HashSet;
+
+pub struct Day06 {
+    datastream: String,
+}
+
+impl Solution for Day06 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            datastream: input.trim().to_string(),
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        distinct_message_index(&self.datastream, 4).map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(&self) -> Answer {
+        distinct_message_index(&self.datastream, 14).map_or(Answer::Unsolved, Answer::from)
+    }
+}
+
+/// The number of characters up to and including the first `window_size` in a
+/// row that are all different, if there are any. The stream can hold any
+/// character, not just letters.
+fn distinct_message_index(input: &str, window_size: usize) -> Option<usize> {
+    let chars: Vec<char> = input.chars().collect();
+    chars
+        .windows(window_size)
+        .position(|window| window.iter().collect::<HashSet<_>>().len() == window_size)
+        .map(|left| left + window_size)
+}
@@ -0,0 +1,249 @@
+//! Day 7: No Space Left On Device. A terminal session is replayed into a
+//! [`Filesystem`] to find the directories worth deleting.
+use aoc_common::{parse_field, parse_lines, Answer, ParseError, Result, Solution};
+use std::{collections::HashMap, fmt, str::FromStr};
+
+/// A line of the terminal output: a command typed, or a line of what `ls`
+/// listed.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Cd(String),
+    Ls,
+    Dir(String),
+    /// A file's name and size.
+    File(String, i32),
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(item: &str) -> std::result::Result<Self, Self::Err> {
+        let parts: Vec<&str> = item.split_whitespace().collect();
+        // println!("{:?}", parts);
+        match parts[..] {
+            ["$", "cd", dir] => Ok(Self::Cd(dir.to_string())),
+            ["$", "ls"] => Ok(Self::Ls),
+            ["$", ..] => Err(ParseError::new(item, item, "$ cd <dir> or $ ls")),
+            // file or dir
+            ["dir", name] => Ok(Self::Dir(name.to_string())),
+            [found, name] => {
+                let size = parse_field(item, found, "a file size or dir")?;
+                if size < 0 {
+                    return Err(ParseError::new(item, found, "a file size or dir"));
+                }
+                Ok(Self::File(name.to_string(), size))
+            }
+            _ => Err(ParseError::new(
+                item,
+                item,
+                "$ cd <dir>, $ ls, dir <name> or <size> <name>",
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Cd(dir) => write!(f, "$ cd {dir}"),
+            Self::Ls => write!(f, "$ ls"),
+            Self::Dir(name) => write!(f, "dir {name}"),
+            Self::File(name, size) => write!(f, "{size} {name}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FilesystemItem {
+    File(String, i32),
+    Dir(String, Vec<FilesystemItem>),
+}
+
+/// The directories the terminal session looked into, with what `ls` found
+/// in each.
+#[derive(Debug, Clone, Default)]
+pub struct Filesystem {
+    root: HashMap<String, Vec<FilesystemItem>>,
+    current_dir: Vec<String>,
+}
+
+impl Filesystem {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replays a terminal session.
+    pub fn from_commands(commands: impl IntoIterator<Item = Command>) -> Self {
+        let mut filesystem = Filesystem::new();
+        for c in commands {
+            filesystem.apply(&c);
+        }
+        filesystem
+    }
+
+    pub fn apply(&mut self, command: &Command) {
+        match command {
+            Command::Cd(d) => self.set_current_dir(d),
+            Command::Ls => {}
+            Command::Dir(d) => self.push_dir(d),
+            Command::File(f, s) => self.push_file(f, *s),
+        }
+    }
+
+    /// The total size of every directory, keyed by its path with `/` as "".
+    pub fn dir_sizes(&self) -> HashMap<String, usize> {
+        let mut sizes: HashMap<String, usize> = HashMap::new();
+        for (path, files) in self.root.iter() {
+            let dirs: Vec<&str> = path.split("/").collect();
+            let size = files
+                .iter()
+                .map(|f| {
+                    if let FilesystemItem::File(_, size) = f {
+                        *size as usize
+                    } else {
+                        0
+                    }
+                })
+                .sum();
+
+            for i in 0..dirs.len() {
+                sizes
+                    .entry(dirs[0..=i].iter().cloned().collect::<String>())
+                    .and_modify(|v| *v += size)
+                    .or_insert(size);
+            }
+        }
+        sizes
+    }
+
+    fn current_dir_str(&self) -> String {
+        self.current_dir.join("/").to_string()
+    }
+
+    fn push_file(&mut self, filename: &str, size: i32) {
+        let dir = self.current_dir_str();
+        if let Some(ref mut children) = self.root.get_mut(&dir) {
+            children.push(FilesystemItem::File(filename.to_string(), size));
+        }
+    }
+
+    fn push_dir(&mut self, dir_name: &str) {
+        let dir = self.current_dir_str();
+        if let Some(ref mut children) = self.root.get_mut(&dir) {
+            children.push(FilesystemItem::Dir(dir_name.to_string(), vec![]));
+        }
+    }
+
+    fn set_current_dir(&mut self, dir_name: &str) {
+        if dir_name == ".." {
+            self.current_dir.pop();
+        } else if dir_name == "/" {
+            self.current_dir.push("".to_string());
+            self.root.entry("".to_string()).or_insert(vec![]);
+        } else {
+            self.current_dir.push(dir_name.to_string());
+            self.root.entry(self.current_dir_str()).or_insert(vec![]);
+        }
+    }
+}
+
+pub struct Day07 {
+    // total size of every directory, keyed by its path
+    sizes: HashMap<String, usize>,
+}
+
+impl Day07 {
+    /// The total size of every directory, as [`Filesystem::dir_sizes`].
+    pub fn sizes(&self) -> &HashMap<String, usize> {
+        &self.sizes
+    }
+}
+
+impl Solution for Day07 {
+    fn parse(input: &str) -> Result<Self> {
+        let commands: Vec<Command> = parse_lines(input)?;
+        // everything is sized from the root, so the session has to start there
+        if commands.first() != Some(&Command::Cd("/".to_string())) {
+            let first = input.lines().next().unwrap_or_default();
+            return Err(ParseError::new(input, first, "$ cd / to start").into());
+        }
+        let sizes = Filesystem::from_commands(commands).dir_sizes();
+
+        Ok(Self { sizes })
+    }
+
+    fn part1(&self) -> Answer {
+        let sum = self
+            .sizes
+            .iter()
+            .filter(|(_, v)| **v < 100_000)
+            .map(|(_, v)| v)
+            .sum::<usize>();
+
+        sum.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let Some(outtermost) = self.sizes.get("") else {
+            return Answer::Unsolved;
+        };
+        let free_space = 70_000_000usize.saturating_sub(*outtermost);
+        let needed_free_space = 30_000_000usize.saturating_sub(free_space);
+
+        // no directory being big enough means not even the root will do
+        self.sizes
+            .values()
+            .filter(|s| **s > needed_free_space)
+            .min()
+            .map_or(Answer::Unsolved, |&s| s.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::testing::{check, fuzz, Rng};
+
+    fn command(rng: &mut Rng) -> Command {
+        let name = |rng: &mut Rng| rng.string("abcdefghij.", 1..=8);
+        match rng.below(4) {
+            0 => Command::Cd(match rng.below(3) {
+                0 => "/".to_string(),
+                1 => "..".to_string(),
+                _ => name(rng),
+            }),
+            1 => Command::Ls,
+            2 => Command::Dir(name(rng)),
+            _ => Command::File(name(rng), rng.range(0..=i32::MAX as i64) as i32),
+        }
+    }
+
+    #[test]
+    fn test_command() {
+        check(500, command, |c| {
+            assert_eq!(c.to_string().parse::<Command>().unwrap(), *c)
+        });
+
+        fuzz(
+            2000,
+            &["$ cd /", "$ ls", "dir a", "14848514 b.txt"],
+            |item| {
+                let _ = item.parse::<Command>();
+            },
+        );
+        assert!("-5 a".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn test_root() {
+        let err = Day07::parse("$ ls\n100 a").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected $ cd / to start, found \"$ ls\""
+        );
+        assert!(Day07::parse("").is_err());
+
+        let day = Day07::parse("$ cd /\n$ ls\n100 a\n").unwrap();
+        assert_eq!((day.part1(), day.part2()), (100.into(), 100.into()));
+    }
+}
@@ -0,0 +1,61 @@
+use aoc_common::{
+    geometry::Direction,
+    grid::{Grid, Pos},
+    Answer, Result, Solution,
+};
+use std::collections::HashMap;
+
+pub struct Day08 {
+    grid: Grid<u32>,
+}
+
+impl Solution for Day08 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            grid: Grid::parse(input, "a tree height, 0-9", |c| c.to_digit(10))?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        let grid = &self.grid;
+
+        // trees on the edge have nothing in the way in at least one direction
+        let mut visible_count = 0;
+        for (p, height) in grid.iter() {
+            let is_visible = Direction::ALL
+                .into_iter()
+                .any(|d| grid.ray(p, d.offset()).all(|t| grid[t] < *height));
+
+            if is_visible {
+                visible_count += 1;
+            }
+        }
+
+        visible_count.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let grid = &self.grid;
+
+        let mut scores: HashMap<Pos, usize> = HashMap::new();
+        for (p, height) in grid.iter() {
+            let mut score = 1;
+            for d in Direction::ALL {
+                let mut count = 0;
+                for t in grid.ray(p, d.offset()) {
+                    count += 1;
+                    if grid[t] >= *height {
+                        break;
+                    }
+                }
+                score *= count;
+            }
+
+            scores.insert(p, score);
+        }
+
+        let max = scores.iter().max_by(|(_, v1), (_, v2)| v1.cmp(v2)).unwrap();
+
+        (*max.1).into()
+    }
+}
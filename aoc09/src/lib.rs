@@ -0,0 +1,70 @@
+use aoc_common::{
+    geometry::{Direction, Point2},
+    parse_field, parse_lines, Answer, ParseError, Result, Solution,
+};
+use std::{collections::HashSet, str::FromStr};
+
+type Point = Point2<i32>;
+
+#[derive(Debug, Clone)]
+struct Command {
+    dir: Direction,
+    steps: i32,
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(item: &str) -> std::result::Result<Self, Self::Err> {
+        let (dir, n) = item
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(item, item, "a direction and a step count"))?;
+        let steps = parse_field(item, n, "a step count")?;
+        let dir = parse_field(item, dir, "a direction, U, D, L or R")?;
+        Ok(Self { dir, steps })
+    }
+}
+
+pub struct Day09 {
+    commands: Vec<Command>,
+}
+
+impl Solution for Day09 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            commands: parse_lines(input)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        tail_positions(&self.commands, 2).len().into()
+    }
+
+    fn part2(&self) -> Answer {
+        tail_positions(&self.commands, 10).len().into()
+    }
+}
+
+/// Every position the last of `knots` knots visits while the head follows
+/// the commands.
+fn tail_positions(commands: &[Command], knots: usize) -> HashSet<Point> {
+    let mut rope = vec![Point::default(); knots];
+    let mut visited = HashSet::from([Point::default()]);
+    for c in commands {
+        for _ in 0..c.steps {
+            rope[0] += c.dir.offset();
+
+            // each knot stays put while it still touches the one before it
+            for k in 1..knots {
+                if rope[k - 1].chebyshev(rope[k]) > 1 {
+                    let d = rope[k - 1] - rope[k];
+                    rope[k] += d.signum();
+                }
+            }
+
+            visited.insert(rope[knots - 1]);
+        }
+    }
+
+    visited
+}
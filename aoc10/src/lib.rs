@@ -0,0 +1,146 @@
+use aoc_common::{
+    grid::{Coord, Grid, Pos},
+    parse_field, parse_lines,
+    visualize::{Colour, Frame, Screen, Visualize},
+    Answer, ParseError, Result, Solution,
+};
+use std::str::FromStr;
+
+#[derive(Debug)]
+enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(item: &str) -> std::result::Result<Self, Self::Err> {
+        let parts: Vec<&str> = item.split_whitespace().collect();
+        match parts[..] {
+            ["noop"] => Ok(Self::Noop),
+            ["addx", arg] => Ok(Self::Addx(parse_field(item, arg, "a number to add")?)),
+            _ => Err(ParseError::new(item, item, "noop or addx <n>")),
+        }
+    }
+}
+
+impl Instruction {
+    fn cycles(&self) -> usize {
+        match self {
+            Self::Noop => 1,
+            Self::Addx(_) => 2,
+        }
+    }
+}
+
+pub struct Day10 {
+    instructions: Vec<Instruction>,
+}
+
+impl Solution for Day10 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            instructions: parse_lines(input)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut cycle = 0;
+        let mut x = 1;
+        let mut signal = 0;
+        for i in &self.instructions {
+            for _ in 0..i.cycles() {
+                // the signal is sampled *during* the cycle, before addx lands
+                cycle += 1;
+                if cycle % 40 == 20 {
+                    signal += cycle * x;
+                }
+            }
+
+            if let Instruction::Addx(v) = i {
+                x += v;
+            }
+        }
+
+        signal.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let pixels = self.crt(|_, _| ());
+        let screen: Vec<String> = (0..pixels.height())
+            .map(|y| {
+                pixels
+                    .row(y)
+                    .map(|(_, lit)| if *lit { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        screen.join("\n").into()
+    }
+}
+
+const WIDTH: usize = 40;
+const HEIGHT: usize = 6;
+
+impl Day10 {
+    /// Runs the program, drawing a pixel a cycle. Calls `drawn` with the
+    /// screen and the sprite's position after each one.
+    fn crt(&self, mut drawn: impl FnMut(&Grid<bool>, i32)) -> Grid<bool> {
+        let mut pixel: usize = 0;
+        let mut x: i32 = 1;
+        let mut pixels = Grid::new(WIDTH, HEIGHT, false);
+        for i in &self.instructions {
+            for _ in 0..i.cycles() {
+                let pos = Pos::new(pixel % WIDTH, pixel / WIDTH);
+                if pixels.contains(pos) {
+                    pixels[pos] = (pos.x as i32 - x).abs() <= 1;
+                    drawn(&pixels, x);
+                }
+                pixel += 1;
+            }
+
+            if let Instruction::Addx(v) = i {
+                x += v;
+            }
+        }
+
+        pixels
+    }
+}
+
+impl Visualize for Day10 {
+    fn visualize(&self, screen: &mut Screen) {
+        let mut cycle = 0;
+        self.crt(|pixels, x| {
+            cycle += 1;
+            let row = (cycle - 1) / WIDTH;
+            let mut frame = Frame::new(
+                Coord::new(0, 0),
+                Coord::new(WIDTH as i64 - 1, HEIGHT as i64),
+            );
+            for (p, lit) in pixels.iter() {
+                let p = Coord::new(p.x as i64, p.y as i64);
+                // only what the beam has swept so far
+                if p.y as usize * WIDTH + p.x as usize >= cycle {
+                    continue;
+                }
+                if *lit {
+                    frame.set(p, '#', Colour::Green);
+                } else {
+                    frame.set(p, '.', Colour::Grey);
+                }
+            }
+            // the sprite, on the row being drawn
+            for dx in -1..=1 {
+                frame.set(
+                    Coord::new((x + dx) as i64, HEIGHT as i64),
+                    '=',
+                    Colour::Yellow,
+                );
+            }
+            frame.set_caption(format!("cycle {cycle}, row {}, X={x}", row + 1));
+            screen.show(&frame);
+        });
+    }
+}
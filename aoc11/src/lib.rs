@@ -0,0 +1,223 @@
+use aoc_common::{parse_field, Answer, ParseError, Result, Solution};
+use std::{
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+#[derive(Debug, Clone)]
+enum Operation {
+    Add(u128),
+    Mul(u128),
+    Old,
+}
+
+impl Operation {
+    /// The new worry level, or `None` if it's too big to keep track of.
+    fn apply(&self, old: u128) -> Option<u128> {
+        match self {
+            Operation::Add(n) => old.checked_add(*n),
+            Operation::Mul(n) => old.checked_mul(*n),
+            Operation::Old => old.checked_mul(old),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Monkey {
+    items: Vec<u128>,
+    operation: Operation,
+    test: u128,
+    throw_to: (usize, usize),
+    inspection_count: u128,
+}
+
+// Monkey 0:
+//   Starting items: 79, 98
+//   Operation: new = old * 19
+//   Test: divisible by 23
+//     If true: throw to monkey 2
+//     If false: throw to monkey 3
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(item: &str) -> std::result::Result<Self, Self::Err> {
+        let lines: Vec<&str> = item.lines().map(|l| l.trim()).collect();
+        // the rest of line `n`, after the text every monkey has there
+        let field = |n: usize, prefix: &str| {
+            let l = lines
+                .get(n)
+                .ok_or_else(|| ParseError::missing(item, format!("a line starting {prefix:?}")))?;
+            l.strip_prefix(prefix)
+                .ok_or_else(|| ParseError::new(item, l, format!("a line starting {prefix:?}")))
+        };
+
+        let items = field(1, "Starting items:")?
+            .split(',')
+            .map(str::trim)
+            .filter(|i| !i.is_empty())
+            .map(|i| parse_field(item, i, "a worry level"))
+            .collect::<std::result::Result<_, _>>()?;
+
+        let op = field(2, "Operation: new = old ")?;
+        let operation = match op.split_once(' ') {
+            Some(("*", "old")) => Operation::Old,
+            Some(("*", n)) => Operation::Mul(parse_field(item, n, "a number or old")?),
+            Some(("+", n)) => Operation::Add(parse_field(item, n, "a number")?),
+            _ => return Err(ParseError::new(item, op, "* <n>, * old or + <n>")),
+        };
+
+        let test = field(3, "Test: divisible by ")?;
+        let divisor = parse_field(item, test, "a divisor")?;
+        if divisor == 0 {
+            return Err(ParseError::new(item, test, "a divisor above 0"));
+        }
+        let if_true = field(4, "If true: throw to monkey ")?;
+        let if_false = field(5, "If false: throw to monkey ")?;
+
+        Ok(Self {
+            items,
+            operation,
+            test: divisor,
+            throw_to: (
+                parse_field(item, if_true, "a monkey number")?,
+                parse_field(item, if_false, "a monkey number")?,
+            ),
+            inspection_count: 0,
+        })
+    }
+}
+
+pub struct Day11 {
+    monkeys: Vec<Monkey>,
+}
+
+impl Day11 {
+    fn monkeys(&self) -> Vec<Arc<Mutex<Monkey>>> {
+        self.monkeys
+            .iter()
+            .map(|m| Arc::new(Mutex::new(m.clone())))
+            .collect()
+    }
+}
+
+impl Solution for Day11 {
+    fn parse(input: &str) -> Result<Self> {
+        let mut monkeys = vec![];
+        let mut blocks = vec![];
+        let mut line = 1;
+        for block in input.split("\n\n") {
+            let monkey: Monkey = block.parse().map_err(|e: ParseError| e.at_line(line))?;
+            monkeys.push(monkey);
+            blocks.push((block, line));
+            line += block.lines().count() + 1;
+        }
+
+        for (m, (block, line)) in monkeys.iter().zip(blocks) {
+            for (n, to) in [(4, m.throw_to.0), (5, m.throw_to.1)] {
+                if to >= monkeys.len() {
+                    let l = block.lines().nth(n).unwrap_or_default();
+                    let target = l.rsplit(' ').next().unwrap_or_default();
+                    let expected = format!("a monkey number below {}", monkeys.len());
+                    let err = ParseError::new(block, target, expected);
+                    return Err(err.at_line(line).into());
+                }
+            }
+        }
+
+        Ok(Self { monkeys })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut monkeys = self.monkeys();
+
+        for _ in 0..20 {
+            for m in 0..monkeys.len() {
+                let monkey = monkeys[m].clone();
+                while !monkey.lock().unwrap().items.is_empty() {
+                    let item = monkey.lock().unwrap().items.remove(0);
+                    let Some(new_item) = monkey.lock().unwrap().operation.apply(item) else {
+                        return Answer::Unsolved;
+                    };
+                    let new_item = new_item / 3;
+                    monkey.lock().unwrap().inspection_count += 1;
+                    if new_item % monkey.lock().unwrap().test == 0u128 {
+                        monkeys[monkey.lock().unwrap().throw_to.0]
+                            .lock()
+                            .unwrap()
+                            .items
+                            .push(new_item);
+                    } else {
+                        monkeys[monkey.lock().unwrap().throw_to.1]
+                            .lock()
+                            .unwrap()
+                            .items
+                            .push(new_item);
+                    }
+                }
+            }
+        }
+
+        monkeys.sort_by(|a, b| {
+            b.lock()
+                .unwrap()
+                .inspection_count
+                .cmp(&a.lock().unwrap().inspection_count)
+        });
+
+        let prod: u128 = monkeys
+            .iter()
+            .take(2)
+            .map(|m| m.lock().unwrap().inspection_count)
+            .product();
+        prod.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let mut monkeys = self.monkeys();
+
+        let mut tests = monkeys.iter().map(|m| m.lock().unwrap().test);
+        let Some(mod_val) = tests.try_fold(1u128, |product, test| product.checked_mul(test)) else {
+            return Answer::Unsolved;
+        };
+
+        for _ in 0..10_000 {
+            for m in 0..monkeys.len() {
+                let monkey = monkeys[m].clone();
+                while !monkey.lock().unwrap().items.is_empty() {
+                    let item = monkey.lock().unwrap().items.remove(0);
+                    let Some(new_item) = monkey.lock().unwrap().operation.apply(item) else {
+                        return Answer::Unsolved;
+                    };
+                    monkey.lock().unwrap().inspection_count += 1;
+                    if new_item % monkey.lock().unwrap().test == 0u128 {
+                        monkeys[monkey.lock().unwrap().throw_to.0]
+                            .lock()
+                            .unwrap()
+                            .items
+                            .push(new_item % mod_val);
+                    } else {
+                        monkeys[monkey.lock().unwrap().throw_to.1]
+                            .lock()
+                            .unwrap()
+                            .items
+                            .push(new_item % mod_val);
+                    }
+                }
+            }
+        }
+
+        monkeys.sort_by(|a, b| {
+            b.lock()
+                .unwrap()
+                .inspection_count
+                .cmp(&a.lock().unwrap().inspection_count)
+        });
+
+        let prod: u128 = monkeys
+            .iter()
+            .take(2)
+            .map(|m| m.lock().unwrap().inspection_count)
+            .product();
+        prod.into()
+    }
+}
@@ -0,0 +1,120 @@
+use aoc_common::{
+    debug,
+    grid::{Coord, Grid, Pos},
+    image::Rgb,
+    search::{Found, Search},
+    visualize::{Colour, Frame, Screen, Visualize},
+    Answer, ParseError, Result, Solution,
+};
+
+fn height(grid: &Grid<char>, p: Pos) -> i32 {
+    if grid[p].is_lowercase() {
+        grid[p] as i32
+    } else if grid[p] == 'S' {
+        'a' as i32
+    } else if grid[p] == 'E' {
+        'z' as i32
+    } else {
+        -1
+    }
+}
+
+pub struct Day12 {
+    grid: Grid<char>,
+    start: Pos,
+    end: Pos,
+}
+
+impl Solution for Day12 {
+    fn parse(input: &str) -> Result<Self> {
+        let grid = Grid::parse(input, "a height a-z, S or E", |c| {
+            (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)
+        })?;
+
+        let find = |key| {
+            grid.position(|c| *c == key).ok_or_else(|| {
+                let expected = format!("the heightmap to contain {key}");
+                ParseError::missing(input, expected)
+            })
+        };
+        let start = find('S')?;
+        let end = find('E')?;
+
+        Ok(Self { grid, start, end })
+    }
+
+    fn part1(&self) -> Answer {
+        let grid = &self.grid;
+        let (start, end) = (self.start, self.end);
+
+        debug!("Start: {:?}", start);
+        debug!("End: {:?}", end);
+
+        find_shortest_path(grid, Search::from(start), end)
+            .map_or(Answer::Unsolved, |f| f.cost.into())
+    }
+
+    fn part2(&self) -> Answer {
+        let grid = &self.grid;
+        let (start, end) = (self.start, self.end);
+
+        debug!("Start: {:?}", start);
+        debug!("End: {:?}", end);
+
+        // every lowest square at once, the search finds whichever is nearest
+        find_shortest_path(grid, Search::from_all(lowest(grid)), end)
+            .map_or(Answer::Unsolved, |f| f.cost.into())
+    }
+}
+
+fn lowest(grid: &Grid<char>) -> impl Iterator<Item = Pos> + '_ {
+    grid.iter()
+        .filter(|(_, cell)| **cell == 'a' || **cell == 'S')
+        .map(|(p, _)| p)
+}
+
+fn find_shortest_path(
+    grid: &Grid<char>,
+    search: Search<Pos>,
+    end: Pos,
+) -> Option<Found<Pos, usize>> {
+    let climbable = |from: Pos, to: Pos| height(grid, to) - height(grid, from) <= 1;
+    search.bfs(
+        |&p| grid.neighbours4(p).filter(move |&n| climbable(p, n)),
+        |&p| p == end,
+    )
+}
+
+impl Visualize for Day12 {
+    /// The heightmap shaded from blue valleys to red peaks, with the route
+    /// from S and then the shortest hike from any low square traced over it.
+    fn visualize(&self, screen: &mut Screen) {
+        let grid = &self.grid;
+        let searches = [
+            ("from S", Search::from(self.start)),
+            ("from the nearest a", Search::from_all(lowest(grid))),
+        ];
+
+        for (caption, search) in searches {
+            let max = Coord::new(grid.width() as i64 - 1, grid.height() as i64 - 1);
+            let mut frame = Frame::new(Coord::new(0, 0), max);
+            let coord = |p: Pos| Coord::new(p.x as i64, p.y as i64);
+            for (p, c) in grid.iter() {
+                let t = (height(grid, p) - 'a' as i32) as f64 / 25.0;
+                frame.set(coord(p), *c, Rgb::gradient(t).into());
+            }
+
+            let found = find_shortest_path(grid, search.with_path(), self.end);
+            let path = found
+                .as_ref()
+                .and_then(|f| f.path.as_deref())
+                .unwrap_or_default();
+            for p in path {
+                frame.set(coord(*p), '*', Colour::Rgb(255, 255, 255));
+            }
+
+            frame.set_caption(format!("{caption}: {} steps", path.len().saturating_sub(1)));
+            screen.show(&frame);
+        }
+    }
+}
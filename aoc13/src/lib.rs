@@ -0,0 +1,319 @@
+//! Day 13: Distress Signal. Pairs of nested [`Packet`]s, put in order.
+use aoc_common::{debug, trace, Answer, ParseError, Result, Solution};
+use std::{cmp::Ordering, fmt, str::FromStr};
+
+/// A packet of the distress signal: a number, or a list of packets. Packets
+/// order as the puzzle sets out, a number comparing with a list as if it were
+/// a list of just that number.
+#[derive(Debug, Clone, Eq)]
+pub enum Packet {
+    Number(u32),
+    List(Vec<Packet>),
+}
+
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::List(l), Self::List(r)) => l == r,
+            (Self::Number(l), Self::Number(r)) => l == r,
+            (Self::List(l), Self::Number(r)) => l == &vec![Packet::Number(*r)],
+            (Self::Number(l), Self::List(r)) => &vec![Packet::Number(*l)] == r,
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::List(l), Packet::List(r)) => l.cmp(r),
+            (Packet::List(l), Packet::Number(r)) => l.cmp(&vec![Packet::Number(*r)]),
+            (Packet::Number(l), Packet::List(r)) => vec![Packet::Number(*l)].cmp(r),
+            (Packet::Number(l), Packet::Number(r)) => l.cmp(r),
+        }
+    }
+}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Packet::Number(n) => write!(f, "{n}"),
+            Packet::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(item: &str) -> std::result::Result<Self, Self::Err> {
+        parse_packet(item)
+    }
+}
+
+fn parse_packet(item: &str) -> std::result::Result<Packet, ParseError> {
+    let chars: Vec<char> = item.chars().collect();
+    let mut pos = 0;
+    let packet = match chars.get(pos) {
+        Some('[') => {
+            pos += 1;
+            parse_list(&mut pos, &chars)?
+        }
+        Some(cc) if cc.is_ascii_digit() => parse_digits(&mut pos, &chars)?,
+        _ => return Err(unexpected(&chars, pos, "[ or a number")),
+    };
+
+    if pos < chars.len() {
+        return Err(unexpected(&chars, pos, "the end of the packet"));
+    }
+
+    Ok(packet)
+}
+
+fn parse_list(pos: &mut usize, chars: &[char]) -> std::result::Result<Packet, ParseError> {
+    let mut items = vec![];
+    loop {
+        match chars.get(*pos) {
+            Some('[') => {
+                *pos += 1;
+                items.push(parse_list(pos, chars)?)
+            }
+            Some(',') => {
+                *pos += 1;
+                continue;
+            }
+            Some(']') => {
+                *pos += 1;
+                break;
+            }
+            Some(c) if c.is_ascii_digit() => {
+                items.push(parse_digits(pos, chars)?);
+            }
+            _ => return Err(unexpected(chars, *pos, "[, ], a comma or a number")),
+        };
+    }
+
+    Ok(Packet::List(items))
+}
+
+fn parse_digits(pos: &mut usize, chars: &[char]) -> std::result::Result<Packet, ParseError> {
+    let start = *pos;
+    let mut result = String::new();
+    while let Some(c) = chars.get(*pos).filter(|c| c.is_ascii_digit()) {
+        *pos += 1;
+        result.push(*c)
+    }
+
+    let n = result.parse().map_err(|_| ParseError {
+        line: 1,
+        column: start + 1,
+        text: result.clone(),
+        expected: "a number that fits in a u32".to_string(),
+    })?;
+    Ok(Packet::Number(n))
+}
+
+/// The packet parsers walk chars rather than bytes, so they place their own
+/// errors instead of going through `ParseError::new`.
+fn unexpected(chars: &[char], pos: usize, expected: &str) -> ParseError {
+    ParseError {
+        line: 1,
+        column: pos + 1,
+        text: chars.get(pos).map(|c| c.to_string()).unwrap_or_default(),
+        expected: expected.to_string(),
+    }
+}
+
+pub struct Day13 {
+    pairs: Vec<(Packet, Packet)>,
+}
+
+impl Day13 {
+    pub fn pairs(&self) -> &[(Packet, Packet)] {
+        &self.pairs
+    }
+}
+
+impl Solution for Day13 {
+    fn parse(input: &str) -> Result<Self> {
+        let mut pairs = vec![];
+        let mut line = 1;
+        for block in input.split("\n\n") {
+            let lines: Vec<&str> = block.lines().collect();
+            if lines.len() != 2 {
+                let err = ParseError::new(block, block, "a pair of packets");
+                return Err(err.at_line(line).into());
+            }
+
+            let left = parse_packet(lines[0]).map_err(|e| e.at_line(line))?;
+            let right = parse_packet(lines[1]).map_err(|e| e.at_line(line + 1))?;
+            pairs.push((left, right));
+            line += lines.len() + 1;
+        }
+
+        Ok(Self { pairs })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut pairs = self.pairs.clone();
+
+        let mut valid_pairs = vec![];
+        for (i, p) in pairs.iter_mut().enumerate() {
+            let is_valid = check_order(&mut p.0, &mut p.1);
+            // let is_valid = p.0.cmp(&p.1);
+            if is_valid == Ordering::Less {
+                valid_pairs.push(i + 1);
+            }
+        }
+
+        trace!("{:#?}", pairs);
+        trace!("{:#?}", valid_pairs);
+
+        let sum: usize = valid_pairs.iter().sum();
+        sum.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let mut packets: Vec<Packet> = self
+            .pairs
+            .iter()
+            .flat_map(|(l, r)| vec![l.clone(), r.clone()])
+            .collect();
+
+        let packet_2 = Packet::List(vec![Packet::List(vec![Packet::Number(2)])]);
+        packets.push(packet_2.clone());
+        let packet_6 = Packet::List(vec![Packet::List(vec![Packet::Number(6)])]);
+        packets.push(packet_6.clone());
+
+        debug!("{}", packets.len());
+
+        packets.sort();
+
+        trace!("{:#?}", packets);
+
+        let index_2 = packets
+            .iter()
+            .enumerate()
+            .find(|(_i, p)| p == &&packet_2)
+            .unwrap();
+        let index_6 = packets
+            .iter()
+            .enumerate()
+            .find(|(_i, p)| p == &&packet_6)
+            .unwrap();
+
+        debug!("{:?}, {:?}", index_2, index_6);
+        let key = (index_2.0 + 1) * (index_6.0 + 1);
+
+        key.into()
+    }
+}
+
+fn check_order(left: &mut Packet, right: &mut Packet) -> Ordering {
+    match (left, right) {
+        (Packet::Number(l), Packet::Number(r)) => l.cmp(&r), //Ordering::Greater,
+        (Packet::List(l), Packet::List(r)) => {
+            let mut i = 0;
+            let mut j = 0;
+            while i < l.len() && j < r.len() {
+                let v = check_order(&mut l[i], &mut r[j]);
+                if v == Ordering::Greater || v == Ordering::Less {
+                    return v;
+                }
+
+                i += 1;
+                j += 1;
+            }
+
+            if i == l.len() {
+                if j == r.len() {
+                    return Ordering::Equal;
+                }
+                return Ordering::Less;
+            }
+
+            if j >= r.len() {
+                return Ordering::Greater;
+            }
+
+            Ordering::Equal
+        }
+        (Packet::Number(l), Packet::List(r)) => check_order(
+            &mut Packet::List(vec![Packet::Number(*l)]),
+            &mut Packet::List(r.clone()),
+        ),
+        (Packet::List(l), Packet::Number(r)) => check_order(
+            &mut Packet::List(l.clone()),
+            &mut Packet::List(vec![Packet::Number(*r)]),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::testing::{check, fuzz, Rng};
+
+    fn packet(rng: &mut Rng, depth: usize) -> Packet {
+        if depth == 0 || rng.one_in(3) {
+            return Packet::Number(rng.range(0..=u32::MAX as i64) as u32);
+        }
+        let len = rng.below(5);
+        Packet::List((0..len).map(|_| packet(rng, depth - 1)).collect())
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // compare the Debug forms, as == takes 1 and [1] to be the same
+        check(
+            500,
+            |rng| packet(rng, 4),
+            |p| {
+                let parsed = parse_packet(&p.to_string()).unwrap();
+                assert_eq!(format!("{parsed:?}"), format!("{p:?}"));
+            },
+        );
+
+        check(
+            500,
+            |rng| (packet(rng, 3), packet(rng, 3)),
+            |(l, r)| {
+                assert_eq!(check_order(&mut l.clone(), &mut r.clone()), l.cmp(r));
+            },
+        );
+
+        fuzz(
+            2000,
+            &["[1,[2,[3,[4,[5,6,7]]]],8,9]", "[[]]", "10"],
+            |item| {
+                let _ = parse_packet(item);
+            },
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        let packet = parse_packet("[]").unwrap();
+        assert_eq!(format!("{packet:?}"), "List([])");
+
+        let packet = parse_packet("[[1],4]").unwrap();
+        assert_eq!(
+            format!("{packet:?}"),
+            "List([List([Number(1)]), Number(4)])"
+        );
+    }
+}
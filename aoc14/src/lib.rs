@@ -0,0 +1,182 @@
+use aoc_common::{
+    grid::{Coord, SparseGrid},
+    parse_field,
+    visualize::{Colour, Frame, Screen, Visualize},
+    Answer, ParseError, Result, Solution,
+};
+
+type Point = Coord;
+
+#[derive(Debug)]
+struct Path {
+    start: Point,
+    end: Point,
+}
+
+fn parse_point(l: &str, p: &str) -> std::result::Result<Point, ParseError> {
+    let (x, y) = p
+        .split_once(',')
+        .ok_or_else(|| ParseError::new(l, p, "a point like 498,4"))?;
+    Ok(Point::new(
+        parse_field(l, x, "an x coordinate")?,
+        parse_field(l, y, "a y coordinate")?,
+    ))
+}
+
+pub struct Day14 {
+    paths: Vec<Path>,
+}
+
+impl Solution for Day14 {
+    fn parse(input: &str) -> Result<Self> {
+        let mut paths = vec![];
+        for (i, l) in input.lines().enumerate() {
+            let mut points = vec![];
+            for p in l.split(" -> ") {
+                points.push(parse_point(l, p).map_err(|e| e.at_line(i + 1))?);
+            }
+
+            let mut start = points[0];
+            for (p, text) in points.iter().zip(l.split(" -> ")).skip(1) {
+                // a diagonal would never reach its end a step at a time
+                if p.x != start.x && p.y != start.y {
+                    let expected = "a point in line with the one before";
+                    return Err(ParseError::new(l, text, expected).at_line(i + 1).into());
+                }
+                paths.push(Path { start, end: *p });
+                start = *p;
+            }
+        }
+
+        if paths.is_empty() {
+            return Err(ParseError::missing(input, "a path of rock, like 498,4 -> 498,6").into());
+        }
+
+        Ok(Self { paths })
+    }
+
+    fn part1(&self) -> Answer {
+        self.pour(false, |_| ()).into()
+    }
+
+    fn part2(&self) -> Answer {
+        self.pour(true, |_| ()).into()
+    }
+}
+
+const SOURCE: Point = Point::new(500, 0);
+
+impl Day14 {
+    fn cave(&self) -> SparseGrid<char> {
+        let mut grid = SparseGrid::new();
+
+        for p in &self.paths {
+            let d = (p.end - p.start).signum();
+            let mut s = p.start;
+            loop {
+                // same row
+                grid.insert(s, '#');
+                if s == p.end {
+                    break;
+                }
+
+                s += d;
+            }
+        }
+
+        grid
+    }
+
+    /// Drops sand until it either falls into the abyss or, when there is a
+    /// floor, blocks the source. Returns how many grains came to rest, and
+    /// calls `rested` with the cave after each one.
+    fn pour(&self, floor: bool, mut rested: impl FnMut(&SparseGrid<char>)) -> usize {
+        let mut grid = self.cave();
+
+        // find bottom
+        let (_, max_row) = grid.bounds().unwrap();
+        let floor = floor.then_some(max_row.y + 2);
+
+        let possibilities = [Point::new(0, 1), Point::new(-1, 1), Point::new(1, 1)];
+
+        let mut count = 0;
+        'outer: loop {
+            // each sand unit
+            let mut sand = SOURCE;
+            loop {
+                // move until it comes to rest
+                let prev = sand;
+                for p in &possibilities {
+                    let test_next = sand + *p;
+                    if !grid.contains(test_next) && floor.is_none_or(|f| test_next.y < f) {
+                        sand = test_next;
+                        break;
+                    }
+                }
+
+                if floor.is_none() && sand.y > max_row.y {
+                    break 'outer;
+                }
+
+                if prev == sand {
+                    grid.insert(sand, 'o');
+                    count += 1;
+                    rested(&grid);
+                    if sand == SOURCE {
+                        break 'outer;
+                    }
+                    break;
+                }
+            }
+        }
+
+        count
+    }
+}
+
+impl Visualize for Day14 {
+    fn visualize(&self, screen: &mut Screen) {
+        let (min, max) = self.cave().bounds().unwrap();
+        let top = Point::new(min.x, 0);
+        self.pour(false, |grid| {
+            screen.show(&frame(grid, top, max, None));
+        });
+
+        // with the floor the pile grows out as far as it is high
+        let floor = max.y + 2;
+        let (min, max) = (
+            Point::new(SOURCE.x - floor, 0),
+            Point::new(SOURCE.x + floor, floor),
+        );
+        let mut grains = 0;
+        self.pour(true, |grid| {
+            grains += 1;
+            if grains % 50 == 0 {
+                screen.show(&frame(grid, min, max, Some(floor)));
+            }
+        });
+    }
+}
+
+fn frame(grid: &SparseGrid<char>, min: Point, max: Point, floor: Option<i64>) -> Frame {
+    let mut frame = Frame::new(min, max);
+    let sand = grid.iter().filter(|(_, c)| **c == 'o').count();
+    frame.set_caption(format!("{sand} grains of sand"));
+
+    for (p, c) in grid.iter() {
+        let colour = if *c == 'o' {
+            Colour::Yellow
+        } else {
+            Colour::Grey
+        };
+        frame.set(p, *c, colour);
+    }
+    if let Some(floor) = floor {
+        for x in min.x..=max.x {
+            frame.set(Point::new(x, floor), '#', Colour::Grey);
+        }
+    }
+    frame.set(SOURCE, '+', Colour::Red);
+
+    frame
+}
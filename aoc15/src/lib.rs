@@ -0,0 +1,147 @@
+use aoc_common::{
+    geometry::Point2, parse_field, parse_lines, Answer, ParseError, Result, Solution,
+};
+use std::{collections::HashSet, str::FromStr};
+
+type Point = Point2<i64>;
+
+#[derive(Debug)]
+struct Sensor {
+    sensor: Point,
+    beacon: Point,
+}
+
+// Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+impl FromStr for Sensor {
+    type Err = ParseError;
+
+    fn from_str(l: &str) -> std::result::Result<Self, Self::Err> {
+        let rest = l
+            .strip_prefix("Sensor at x=")
+            .ok_or_else(|| ParseError::new(l, l, "Sensor at x="))?;
+        let (s_x, rest) = split(l, rest, ", y=")?;
+        let (s_y, rest) = split(l, rest, ": closest beacon is at x=")?;
+        let (b_x, b_y) = split(l, rest, ", y=")?;
+
+        Ok(Sensor {
+            sensor: Point::new(
+                parse_field(l, s_x, "a coordinate")?,
+                parse_field(l, s_y, "a coordinate")?,
+            ),
+            beacon: Point::new(
+                parse_field(l, b_x, "a coordinate")?,
+                parse_field(l, b_y, "a coordinate")?,
+            ),
+        })
+    }
+}
+
+fn split<'a>(
+    l: &str,
+    rest: &'a str,
+    sep: &str,
+) -> std::result::Result<(&'a str, &'a str), ParseError> {
+    rest.split_once(sep)
+        .ok_or_else(|| ParseError::new(l, rest, format!("a number followed by {sep:?}")))
+}
+
+pub struct Day15 {
+    sensors: Vec<Sensor>,
+    // the example asks about row 10 and a 0..=20 search area, the real
+    // puzzle about row 2,000,000 and 0..=4,000,000
+    row: i64,
+    max: i64,
+}
+
+impl Solution for Day15 {
+    fn parse(input: &str) -> Result<Self> {
+        let sensors: Vec<Sensor> = parse_lines(input)?;
+
+        let small = sensors.iter().all(|s| s.sensor.x <= 20 && s.sensor.y <= 20);
+        let (row, max) = if small {
+            (10, 20)
+        } else {
+            (2_000_000, 4_000_000)
+        };
+
+        Ok(Self { sensors, row, max })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut map = HashSet::new();
+        let row = self.row;
+        for s in &self.sensors {
+            let radius = s.sensor.manhattan(s.beacon);
+
+            let d = (s.sensor.y - row).abs();
+            if d > radius {
+                continue;
+            }
+
+            let remainder = radius - d;
+            let lx = s.sensor.x - remainder;
+            let rx = s.sensor.x + remainder;
+
+            for p in lx..=rx {
+                map.insert(p);
+            }
+        }
+
+        let beacons: HashSet<i64> = HashSet::from_iter(
+            self.sensors
+                .iter()
+                .filter(|s| s.beacon.y == row)
+                .map(|s| s.beacon.x),
+        );
+        (map.len() - beacons.len()).into()
+    }
+
+    fn part2(&self) -> Answer {
+        let row = self.max;
+        let mut rowdata = vec![vec![0..=row]; row as usize + 1];
+        for s in &self.sensors {
+            let radius = s.sensor.manhattan(s.beacon);
+            let top = 0.max(s.sensor.y - radius);
+            let bottom = row.min(s.sensor.y + radius);
+
+            for r in top..=bottom {
+                let dist = (s.sensor.y - r).abs();
+                let min_x = 0.max(s.sensor.x - (radius - dist));
+                let max_x = row.min(s.sensor.x + (radius - dist));
+                let mut new_range = vec![];
+                for rng in &rowdata[r as usize] {
+                    let start = *rng.start();
+                    if start > max_x {
+                        new_range.push(rng.clone());
+                        continue;
+                    }
+
+                    let end = *rng.end();
+                    if end < min_x {
+                        new_range.push(rng.clone());
+                        continue;
+                    }
+
+                    if start < min_x {
+                        new_range.push(start..=min_x - 1);
+                    }
+
+                    if end > max_x {
+                        new_range.push(max_x + 1..=end);
+                    }
+                }
+
+                rowdata[r as usize] = new_range;
+            }
+        }
+
+        for (y, r) in rowdata.iter().enumerate() {
+            if !r.is_empty() {
+                let x = r[0].start();
+                return (x * 4_000_000 + y as i64).into();
+            }
+        }
+
+        Answer::Unsolved
+    }
+}
@@ -0,0 +1,264 @@
+use aoc_common::{parse_field, parse_lines, search, Answer, ParseError, Result, Solution};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, Default)]
+struct Valve {
+    name: String,
+    rate: usize,
+    leads_to: Vec<String>,
+}
+
+// Valve BB has flow rate=13; tunnels lead to valves CC, AA
+impl FromStr for Valve {
+    type Err = ParseError;
+
+    fn from_str(l: &str) -> std::result::Result<Self, Self::Err> {
+        let rest = l
+            .strip_prefix("Valve ")
+            .ok_or_else(|| ParseError::new(l, l, "Valve <name>"))?;
+        let (name, rest) = rest
+            .split_once(" has flow rate=")
+            .ok_or_else(|| ParseError::new(l, rest, "<name> has flow rate=<rate>"))?;
+        let (rate, rest) = rest
+            .split_once("; ")
+            .ok_or_else(|| ParseError::new(l, rest, "<rate>; tunnels lead to valves"))?;
+        let leads_to = rest
+            .strip_prefix("tunnels lead to valves ")
+            .or_else(|| rest.strip_prefix("tunnel leads to valve "))
+            .ok_or_else(|| ParseError::new(l, rest, "tunnels lead to valves <names>"))?;
+
+        Ok(Valve {
+            name: name.to_string(),
+            rate: parse_field(l, rate, "a flow rate")?,
+            leads_to: leads_to.split(", ").map(|v| v.to_string()).collect(),
+        })
+    }
+}
+
+fn shortcuts(start: &str, tunnels: &HashMap<String, Valve>) -> HashMap<String, usize> {
+    let reached =
+        search::Search::from(start).flood_fill(|v| tunnels[*v].leads_to.iter().map(String::as_str));
+
+    reached
+        .into_iter()
+        .filter(|(v, _)| tunnels[*v].rate > 0 && *v != start)
+        .map(|(v, dist)| (v.to_string(), dist))
+        .collect()
+}
+
+#[derive(Debug, Default, Eq, Clone)]
+struct Walk {
+    loc: String,
+    remaining_time: i32,
+    visited: HashSet<String>,
+    helper: bool,
+}
+
+impl PartialEq for Walk {
+    fn eq(&self, other: &Self) -> bool {
+        self.loc == other.loc
+            && self.helper == other.helper
+            && self.remaining_time == other.remaining_time
+            && self.visited == other.visited
+    }
+}
+
+impl std::hash::Hash for Walk {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.loc.hash(state);
+        self.helper.hash(state);
+        self.remaining_time.hash(state);
+        let mut v = self.visited.iter().collect::<Vec<&String>>();
+        v.sort();
+        for s in v {
+            s.hash(state);
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Search {
+    visited: HashMap<Walk, usize>,
+}
+
+impl Search {
+    fn bfs(
+        &mut self,
+        walk: &Walk,
+        tunnels: &HashMap<String, Valve>,
+        shortcuts: &HashMap<String, HashMap<String, usize>>,
+    ) -> usize {
+        if let Some(ans) = self.visited.get(walk) {
+            return *ans;
+        }
+
+        if walk.remaining_time == 0 {
+            return 0;
+        }
+
+        let mut max_flow = if walk.helper {
+            self.bfs(
+                &Walk {
+                    loc: "AA".to_string(),
+                    // remaining_time: walk.remaining_time,
+                    remaining_time: 26,
+                    visited: walk.visited.clone(),
+                    helper: false,
+                },
+                tunnels,
+                shortcuts,
+            )
+        } else {
+            0
+        };
+        if !walk.visited.contains(&walk.loc) {
+            let mut visited = walk.visited.clone();
+            visited.insert(walk.loc.clone());
+
+            let flow = tunnels.get(&walk.loc).unwrap().rate * (walk.remaining_time - 1) as usize;
+
+            max_flow = max_flow.max(
+                self.bfs(
+                    &Walk {
+                        loc: walk.loc.clone(),
+                        remaining_time: walk.remaining_time - 1,
+                        visited,
+                        helper: walk.helper,
+                    },
+                    tunnels,
+                    shortcuts,
+                ) + flow,
+            );
+        }
+
+        // let dest = &tunnels.get(&walk.loc).unwrap();
+
+        // let lookup = shortcuts.get(&walk.loc).unwrap();
+        for (dest, cost) in shortcuts.get(&walk.loc).unwrap() {
+            // let cost = lookup.get(v).unwrap();
+            if *cost < walk.remaining_time as usize {
+                max_flow = max_flow.max(self.bfs(
+                    &Walk {
+                        loc: dest.to_string(),
+                        remaining_time: walk.remaining_time - *cost as i32,
+                        visited: walk.visited.clone(),
+                        helper: walk.helper,
+                    },
+                    tunnels,
+                    shortcuts,
+                ));
+            }
+        }
+
+        self.visited.insert(walk.clone(), max_flow);
+
+        max_flow
+    }
+}
+
+pub struct Day16 {
+    valves: HashMap<String, Valve>,
+    // distance from each valve to every valve worth opening
+    shortcuts: HashMap<String, HashMap<String, usize>>,
+}
+
+impl Solution for Day16 {
+    fn parse(input: &str) -> Result<Self> {
+        let scan: Vec<Valve> = parse_lines(input)?;
+        let valves: HashMap<String, Valve> =
+            scan.iter().map(|v| (v.name.clone(), v.clone())).collect();
+
+        if !valves.contains_key("AA") {
+            return Err(ParseError::missing(input, "a valve named AA to start from").into());
+        }
+
+        for (i, (v, l)) in scan.iter().zip(input.lines()).enumerate() {
+            if let Some(to) = v.leads_to.iter().find(|to| !valves.contains_key(*to)) {
+                let found = &l[l.rfind(to.as_str()).unwrap_or(0)..];
+                let err = ParseError::new(l, found, "the name of a valve in the scan");
+                return Err(err.at_line(i + 1).into());
+            }
+        }
+
+        let mut cache = HashMap::new();
+        for v in valves.keys() {
+            // println!("{v} -> shortcuts are {:?}", shortcuts(v, &valves));
+            cache.insert(v.clone(), shortcuts(v, &valves));
+        }
+
+        Ok(Self {
+            valves,
+            shortcuts: cache,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut search = Search::default();
+        let walker = Walk {
+            loc: "AA".to_string(),
+            remaining_time: 30,
+            visited: HashSet::new(),
+            helper: false,
+        };
+
+        let max_flow = search.bfs(&walker, &self.valves, &self.shortcuts);
+
+        // let mut stack = vec!["AA".to_string()];
+        // let mut open_values = vec![];
+        // let mut timeline = vec![0; 30];
+        // let mut visited = HashSet::new();
+        // let mut opened: HashMap<String, Valve> = HashMap::new();
+        // for t in 0..30 {
+        //     if stack.is_empty() {
+        //         break;
+        //     }
+        //     let v = stack.pop().unwrap();
+        //     let valve = &valves[&v];
+        //     if visited.contains(&v) {
+        //         continue;
+        //     }
+        //
+        //     if valve.rate > 0 && !opened.is_empty() {
+        //         open_values.push(valve.rate);
+        //         for o in &opened {
+        //             open_values.push(o.1.rate);
+        //         }
+        //         opened.clear();
+        //     } else if !opened.contains_key(&valve.name) {
+        //         opened.insert(valve.name.clone(), valve.clone());
+        //     }
+        //
+        //     visited.insert(v);
+        //
+        //     timeline[t] = open_values.iter().sum();
+        //     for lt in &valve.leads_to {
+        //         if visited.contains(lt) {
+        //             continue;
+        //         }
+        //
+        //         stack.push(lt.to_string());
+        //         break;
+        //     }
+        // }
+
+        // println!("{timeline:?}");
+        max_flow.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let mut search = Search::default();
+        let walker = Walk {
+            loc: "AA".to_string(),
+            remaining_time: 26,
+            visited: HashSet::new(),
+            helper: true,
+        };
+
+        let max_flow = search.bfs(&walker, &self.valves, &self.shortcuts);
+
+        max_flow.into()
+    }
+}
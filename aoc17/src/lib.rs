@@ -0,0 +1,364 @@
+use aoc_common::{
+    cycle::extrapolate_within,
+    debug,
+    geometry::{Direction, Point2},
+    visualize::{Colour, Frame, Screen, Visualize},
+    Answer, ParseError, Result, Solution,
+};
+use std::collections::HashSet;
+
+/// A gust of hot gas, `<` or `>`.
+fn parse_gust(c: char) -> std::result::Result<Direction, ParseError> {
+    match c {
+        '<' => Ok(Direction::Left),
+        '>' => Ok(Direction::Right),
+        _ => Err(ParseError::new("", "", "a gust, < or >")),
+    }
+}
+
+// x across the chamber and y up from the floor, the opposite way to the
+// puzzle's drawings
+type Point = Point2<i64>;
+
+#[derive(Debug, Clone, Default, Hash)]
+enum ShapeKind {
+    Star,
+    HorizontalLine,
+    #[default]
+    VerticalLine,
+    L,
+    Square,
+}
+
+#[derive(Debug, Clone, Default, Hash)]
+struct Shape {
+    kind: ShapeKind,
+    points: Vec<Point>,
+    bottom: i64,
+    height: i64,
+    width: i64,
+}
+
+fn make_shapes() -> Vec<Shape> {
+    vec![
+        Shape {
+            kind: ShapeKind::HorizontalLine,
+            points: vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(3, 0),
+            ],
+            bottom: 0,
+            height: 1,
+            width: 4,
+        },
+        Shape {
+            kind: ShapeKind::Star,
+            points: vec![
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(1, 1),
+                Point::new(2, 1),
+                Point::new(1, 2),
+            ],
+            bottom: 0,
+            height: 3,
+            width: 3,
+        },
+        Shape {
+            kind: ShapeKind::L,
+            points: vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(2, 0),
+                Point::new(2, 1),
+                Point::new(2, 2),
+            ],
+            bottom: 0,
+            height: 3,
+            width: 3,
+        },
+        Shape {
+            kind: ShapeKind::VerticalLine,
+            points: vec![
+                Point::new(0, 0),
+                Point::new(0, 1),
+                Point::new(0, 2),
+                Point::new(0, 3),
+            ],
+            bottom: 0,
+            height: 4,
+            width: 4,
+        },
+        Shape {
+            kind: ShapeKind::Square,
+            points: vec![
+                Point::new(0, 0),
+                Point::new(1, 0),
+                Point::new(0, 1),
+                Point::new(1, 1),
+            ],
+            bottom: 0,
+            height: 2,
+            width: 2,
+        },
+    ]
+}
+
+const RIGHT_EDGE: i64 = 7;
+
+/// The rocks that have come to rest, and every point they fill, so a falling
+/// rock is checked against just the points it would move into.
+#[derive(Debug, Clone, Default)]
+struct Chamber {
+    rocks: Vec<Shape>,
+    filled: HashSet<Point>,
+    height: i64,
+}
+
+impl Chamber {
+    fn push(&mut self, shape: Shape) {
+        for p in &shape.points {
+            self.filled.insert(*p);
+            self.height = self.height.max(p.y + 1);
+        }
+        self.rocks.push(shape);
+    }
+
+    fn len(&self) -> usize {
+        self.rocks.len()
+    }
+}
+
+pub struct Day17 {
+    gusts: Vec<Direction>,
+}
+
+impl Solution for Day17 {
+    fn parse(input: &str) -> Result<Self> {
+        let line = input.trim_end();
+        if line.is_empty() {
+            return Err(ParseError::missing(line, "a gust, < or >").into());
+        }
+
+        let mut gusts = vec![];
+        for (pos, c) in line.char_indices() {
+            let gust = parse_gust(c)
+                .map_err(|e| ParseError::new(line, &line[pos..pos + c.len_utf8()], e.expected))?;
+            gusts.push(gust);
+        }
+
+        Ok(Self { gusts })
+    }
+
+    fn part1(&self) -> Answer {
+        let gusts = &self.gusts;
+
+        let shapes = make_shapes();
+
+        let mut chamber = Chamber::default();
+
+        let mut current_shape_index = 0;
+        let mut gust_idx = 0;
+
+        while chamber.len() < 2022 {
+            drop_rock(
+                &mut chamber,
+                &shapes,
+                gusts,
+                &mut current_shape_index,
+                &mut gust_idx,
+            );
+        }
+        // print_chamber(&chamber, None);
+
+        debug!("Rock count: {}", chamber.len());
+
+        chamber.height.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let gusts = &self.gusts;
+        let shapes = make_shapes();
+        let rock_count = 1_000_000_000_000;
+
+        // (chamber, shape index, gust index)
+        let start = (Chamber::default(), 0, 0);
+        let height = extrapolate_within(
+            start,
+            |(chamber, shape_idx, gust_idx)| {
+                drop_rock(chamber, &shapes, gusts, shape_idx, gust_idx);
+            },
+            |(chamber, shape_idx, gust_idx)| {
+                (
+                    shape_idx % shapes.len(),
+                    gust_idx % gusts.len(),
+                    // a surface too deep to key on never repeats
+                    surface(chamber).ok_or(chamber.len()),
+                )
+            },
+            |(chamber, _, _)| chamber.height,
+            rock_count,
+            MAX_ROCKS,
+        );
+
+        match height {
+            Some(height) => height.into(),
+            None => {
+                debug!("the tower didn't repeat within {MAX_ROCKS} rocks");
+                Answer::Unsolved
+            }
+        }
+    }
+}
+
+fn drop_rock(
+    chamber: &mut Chamber,
+    shapes: &[Shape],
+    gusts: &[Direction],
+    current_shape_index: &mut usize,
+    gust_idx: &mut usize,
+) -> Shape {
+    let mut shape = shapes[*current_shape_index % shapes.len()].clone();
+    let start = Point::new(2, chamber.height + 3);
+    translate(chamber, &mut shape, start); // set initial position
+
+    loop {
+        let gust = &gusts[*gust_idx % gusts.len()];
+        *gust_idx += 1;
+        translate(chamber, &mut shape, gust.offset());
+
+        if !translate(chamber, &mut shape, Point::new(0, -1)) {
+            chamber.push(shape.clone());
+            *current_shape_index += 1;
+            break;
+        }
+    }
+
+    shape
+}
+
+/// How many rocks part 2 drops looking for the tower to repeat. The puzzle's
+/// inputs repeat within a few thousand.
+const MAX_ROCKS: usize = 10_000;
+
+/// How far below the top of the tower [`surface`] looks. The puzzle's towers
+/// leave gaps a few rows deep, but a gap open all the way to the floor is as
+/// deep as the tower.
+const MAX_DEPTH: usize = 64;
+
+/// The empty space a falling rock could still get into, flooded down and
+/// sideways from the row above the tower, one bit per column and a row per
+/// step down from there. Whatever is below it can never be reached again, so
+/// this, the next shape and the next gust decide where every later rock lands.
+/// `None` if it goes deeper than [`MAX_DEPTH`].
+fn surface(chamber: &Chamber) -> Option<Vec<u8>> {
+    let height = chamber.height;
+
+    let mut rows: Vec<u8> = vec![];
+    let mut open: Vec<Point> = (0..RIGHT_EDGE).map(|x| Point::new(x, height)).collect();
+    while let Some(p) = open.pop() {
+        let depth = (height - p.y) as usize;
+        let inside = (0..RIGHT_EDGE).contains(&p.x) && p.y >= 0;
+        if !inside
+            || chamber.filled.contains(&p)
+            || rows.get(depth).is_some_and(|r| r & 1 << p.x != 0)
+        {
+            continue;
+        }
+
+        if depth >= MAX_DEPTH {
+            return None;
+        }
+        if rows.len() <= depth {
+            rows.resize(depth + 1, 0);
+        }
+        rows[depth] |= 1 << p.x;
+        for d in [Point::new(-1, 0), Point::new(1, 0), Point::new(0, -1)] {
+            open.push(p + d);
+        }
+    }
+
+    Some(rows)
+}
+
+fn translate(chamber: &Chamber, shape: &mut Shape, d: Point) -> bool {
+    let mut sandbox = shape.points.clone();
+    let mut can_move = true;
+    for p in &mut sandbox {
+        *p += d;
+        // the floor, under every rock and not just the first
+        if p.y < 0 {
+            can_move = false;
+            break;
+        }
+
+        if p.x > RIGHT_EDGE - 1 {
+            can_move = false;
+            break;
+        }
+
+        if p.x < 0 {
+            can_move = false;
+            break;
+        }
+    }
+
+    can_move = can_move && !sandbox.iter().any(|p| chamber.filled.contains(p));
+
+    if can_move {
+        shape.points = sandbox;
+    }
+
+    can_move
+}
+
+impl Visualize for Day17 {
+    fn visualize(&self, screen: &mut Screen) {
+        const ROWS: i64 = 40;
+        let shapes = make_shapes();
+        let mut chamber = Chamber::default();
+        let mut current_shape_index = 0;
+        let mut gust_idx = 0;
+
+        while chamber.len() < 2022 {
+            drop_rock(
+                &mut chamber,
+                &shapes,
+                &self.gusts,
+                &mut current_shape_index,
+                &mut gust_idx,
+            );
+
+            // the top of the tower, drawn the right way up
+            let height = chamber.height;
+            let mut frame = Frame::new(Point::new(-1, 0), Point::new(RIGHT_EDGE, ROWS - 1));
+            frame.set_caption(format!("{} rocks, {height} high", chamber.len()));
+            for y in 0..ROWS {
+                frame.set(Point::new(-1, y), '|', Colour::Grey);
+                frame.set(Point::new(RIGHT_EDGE, y), '|', Colour::Grey);
+            }
+            if height < ROWS {
+                for x in -1..=RIGHT_EDGE {
+                    frame.set(Point::new(x, height), '-', Colour::Grey);
+                }
+            }
+
+            for shape in chamber.rocks.iter().rev().take(ROWS as usize * 2) {
+                let colour = match shape.kind {
+                    ShapeKind::HorizontalLine => Colour::Red,
+                    ShapeKind::Star => Colour::Yellow,
+                    ShapeKind::L => Colour::Green,
+                    ShapeKind::VerticalLine => Colour::Cyan,
+                    ShapeKind::Square => Colour::Magenta,
+                };
+                for p in &shape.points {
+                    frame.set(Point::new(p.x, height - 1 - p.y), '#', colour);
+                }
+            }
+
+            screen.show(&frame);
+        }
+    }
+}
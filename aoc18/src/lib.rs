@@ -0,0 +1,181 @@
+use aoc_common::{
+    debug,
+    geometry::Point3,
+    grid::Coord,
+    parse_field,
+    search::Search,
+    trace,
+    visualize::{Colour, Frame, Screen, Visualize},
+    Answer, ParseError, Result, Solution,
+};
+use std::collections::HashSet;
+
+type Cube = Point3<i32>;
+
+fn parse_cube(item: &str) -> std::result::Result<Cube, ParseError> {
+    let parts = item.split(",").collect::<Vec<&str>>();
+    let [x, y, z] = parts[..] else {
+        return Err(ParseError::new(item, item, "a cube like 2,2,2"));
+    };
+
+    Ok(Cube::new(
+        parse_field(item, x, "an x coordinate")?,
+        parse_field(item, y, "a y coordinate")?,
+        parse_field(item, z, "a z coordinate")?,
+    ))
+}
+
+/// The corners of the box around the droplet, with a layer of air all round.
+fn bounds(lava: &HashSet<Cube>) -> (Cube, Cube) {
+    let mut min = Cube::new(i32::MAX, i32::MAX, i32::MAX);
+    let mut max = Cube::new(i32::MIN, i32::MIN, i32::MIN);
+    for p in lava {
+        min = Cube::new(min.x.min(p.x), min.y.min(p.y), min.z.min(p.z));
+        max = Cube::new(max.x.max(p.x), max.y.max(p.y), max.z.max(p.z));
+    }
+
+    (min - Cube::new(1, 1, 1), max + Cube::new(1, 1, 1))
+}
+
+/// The air the steam can reach, flooding in from a corner of the box around
+/// the droplet.
+fn outside_air(lava: &HashSet<Cube>) -> HashSet<Cube> {
+    let (min, max) = bounds(lava);
+    let inside = |n: &Cube| {
+        (min.x..=max.x).contains(&n.x)
+            && (min.y..=max.y).contains(&n.y)
+            && (min.z..=max.z).contains(&n.z)
+    };
+
+    Search::from(min)
+        .flood_fill(|p| {
+            p.neighbours6()
+                .into_iter()
+                .filter(move |n| inside(n) && !lava.contains(n))
+        })
+        .into_keys()
+        .collect()
+}
+
+pub struct Day18 {
+    cubes: Vec<Cube>,
+}
+
+impl Solution for Day18 {
+    fn parse(input: &str) -> Result<Self> {
+        let cubes = input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| parse_cube(l).map_err(|e| e.at_line(i + 1)))
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(Self { cubes })
+    }
+
+    fn part1(&self) -> Answer {
+        let cubes = &self.cubes;
+        trace!("Cubes: {:?}", cubes);
+        let mut sides: HashSet<Cube> = HashSet::new();
+        let mut total_sides = cubes.len() * 6;
+        debug!("{total_sides}");
+
+        for c in cubes {
+            sides.insert(*c);
+        }
+
+        for c in cubes {
+            for p in c.neighbours6() {
+                if sides.contains(&p) {
+                    total_sides -= 1;
+                }
+            }
+        }
+        total_sides.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let cubes = &self.cubes;
+        // println!("Cubes: {:?}", cubes);
+        let mut sides: HashSet<Cube> = HashSet::new();
+        // let mut total_sides = cubes.len() * 6;
+        // println!("{total_sides}");
+
+        for c in cubes {
+            sides.insert(*c);
+        }
+
+        // for c in &cubes {
+        //     for d in &dirs {
+        //         let p = Point3 {
+        //             x: c.x + d.0,
+        //             y: c.y + d.1,
+        //             z: c.z + d.2,
+        //         };
+        //         if sides.contains(&p) {
+        //             total_sides -= 1;
+        //         }
+        //     }
+        // }
+
+        let air = outside_air(&sides);
+
+        // every face of the droplet that the outside air touches
+        let count: usize = air
+            .iter()
+            .map(|p| p.neighbours6().iter().filter(|n| sides.contains(n)).count())
+            .sum();
+
+        // for c in &cubes {
+        //     let mut surrounded = true;
+        //     for d in &dirs {
+        //         let p = Point3 {
+        //             x: c.x + d.0,
+        //             y: c.y + d.1,
+        //             z: c.z + d.2,
+        //         };
+        //         if !sides.contains(&p) {
+        //             surrounded = false;
+        //             break;
+        //         }
+        //     }
+        //
+        //     if surrounded {
+        //         total_sides -= 6;
+        //     }
+        // }
+        count.into()
+    }
+}
+
+impl Visualize for Day18 {
+    /// The droplet a slice at a time from bottom to top: lava, the air the
+    /// steam reaches, and the pockets trapped inside.
+    fn visualize(&self, screen: &mut Screen) {
+        let lava: HashSet<Cube> = self.cubes.iter().copied().collect();
+        let air = outside_air(&lava);
+        let (min, max) = bounds(&lava);
+
+        for z in min.z..=max.z {
+            let mut frame = Frame::new(
+                Coord::new(min.x as i64, min.y as i64),
+                Coord::new(max.x as i64, max.y as i64),
+            );
+            for y in min.y..=max.y {
+                for x in min.x..=max.x {
+                    let cube = Cube::new(x, y, z);
+                    let (c, colour) = if lava.contains(&cube) {
+                        ('#', Colour::Red)
+                    } else if air.contains(&cube) {
+                        ('.', Colour::Blue)
+                    } else {
+                        ('o', Colour::Yellow)
+                    };
+                    frame.set(Coord::new(x as i64, y as i64), c, colour);
+                }
+            }
+
+            frame.set_caption(format!("z = {z}"));
+            screen.show(&frame);
+        }
+    }
+}
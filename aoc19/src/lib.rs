@@ -0,0 +1,398 @@
+//! Day 19: Not Enough Minerals. A search over which robot to build each
+//! minute, for each [`Blueprint`].
+use aoc_common::{
+    debug, parse_field, parse_lines, pool, trace, Answer, ParseError, Result, Solution,
+};
+use std::{collections::HashSet, str::FromStr};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+enum Robot {
+    #[default]
+    Noop,
+    Ore(i32),
+    Clay(i32),
+    Obsidian(i32, i32),
+    Geode(i32, i32),
+}
+
+/// What each kind of robot costs to build.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Blueprint {
+    id: i32,
+    ore: Robot,
+    clay: Robot,
+    obsidian: Robot,
+    geode: Robot,
+}
+
+impl Blueprint {
+    pub fn id(&self) -> i32 {
+        self.id
+    }
+
+    /// The most geodes that can be cracked in `minutes`, starting with one ore
+    /// robot.
+    pub fn max_geodes(&self, minutes: i32) -> i32 {
+        work(self, minutes)
+    }
+
+    fn ore_cost(&self) -> i32 {
+        match self.ore {
+            Robot::Ore(c) => c,
+            _ => 0,
+        }
+    }
+
+    fn clay_cost(&self) -> i32 {
+        match self.clay {
+            Robot::Clay(c) => c,
+            _ => 0,
+        }
+    }
+
+    fn obsidian_cost(&self) -> (i32, i32) {
+        match self.obsidian {
+            Robot::Obsidian(o, c) => (o, c),
+            _ => (0, 0),
+        }
+    }
+
+    fn obsidian_ore_cost(&self) -> i32 {
+        match self.obsidian {
+            Robot::Obsidian(o, _) => o,
+            _ => 0,
+        }
+    }
+
+    fn obsidian_clay_cost(&self) -> i32 {
+        match self.obsidian {
+            Robot::Obsidian(_, c) => c,
+            _ => 0,
+        }
+    }
+
+    fn geode_cost(&self) -> (i32, i32) {
+        match self.geode {
+            Robot::Geode(ore, obs) => (ore, obs),
+            _ => (0, 0),
+        }
+    }
+
+    fn geode_ore_cost(&self) -> i32 {
+        match self.geode {
+            Robot::Geode(ore, _) => ore,
+            _ => 0,
+        }
+    }
+
+    fn geode_obsidian_cost(&self) -> i32 {
+        match self.geode {
+            Robot::Geode(_, obs) => obs,
+            _ => 0,
+        }
+    }
+
+    fn enough_for_ore_robot(&self, ore: i32) -> bool {
+        ore >= self.ore_cost()
+    }
+
+    fn enough_for_clay_robot(&self, clay: i32) -> bool {
+        clay >= self.clay_cost()
+    }
+
+    fn enough_for_obsidian_robot(&self, ore: i32, clay: i32) -> bool {
+        let (ore_cost, clay_cost) = self.obsidian_cost();
+        if ore_cost > 0 && clay_cost > 0 {
+            ore >= ore_cost && clay >= clay_cost
+        } else {
+            false
+        }
+    }
+
+    fn enough_for_geoode_robot(&self, ore: i32, obsidian: i32) -> bool {
+        let (ore_cost, obsidian_cost) = self.geode_cost();
+        if ore_cost > 0 && obsidian_cost > 0 {
+            ore >= ore_cost && obsidian >= obsidian_cost
+        } else {
+            false
+        }
+    }
+}
+
+// every blueprint reads the same, with numbers in place of the underscores
+const TEMPLATE: &str = "Blueprint _: Each ore robot costs _ ore. \
+    Each clay robot costs _ ore. \
+    Each obsidian robot costs _ ore and _ clay. \
+    Each geode robot costs _ ore and _ obsidian.";
+
+impl FromStr for Blueprint {
+    type Err = ParseError;
+
+    fn from_str(item: &str) -> std::result::Result<Self, Self::Err> {
+        let mut numbers = vec![];
+        let mut words = item.split_whitespace();
+        for expected in TEMPLATE.split_whitespace() {
+            let word = words
+                .next()
+                .ok_or_else(|| ParseError::missing(item, format!("{expected:?}")))?;
+            match expected.strip_prefix('_') {
+                Some(suffix) => {
+                    let n = word
+                        .strip_suffix(suffix)
+                        .ok_or_else(|| ParseError::new(item, word, "a number"))?;
+                    numbers.push(parse_field(item, n, "a number")?);
+                }
+                None if word != expected => {
+                    return Err(ParseError::new(item, word, format!("{expected:?}")));
+                }
+                None => {}
+            }
+        }
+
+        if let Some(word) = words.next() {
+            return Err(ParseError::new(item, word, "the end of the blueprint"));
+        }
+
+        let [num, ore_cost, clay_cost, obs_ore_cost, obs_clay_cost, geode_ore_cost, geode_clay_cost] =
+            numbers[..]
+        else {
+            unreachable!("the template has seven numbers");
+        };
+
+        Ok(Self {
+            id: num,
+            ore: Robot::Ore(ore_cost),
+            clay: Robot::Clay(clay_cost),
+            obsidian: Robot::Obsidian(obs_ore_cost, obs_clay_cost),
+            geode: Robot::Geode(geode_ore_cost, geode_clay_cost),
+        })
+    }
+}
+
+pub struct Day19 {
+    blueprints: Vec<Blueprint>,
+}
+
+impl Day19 {
+    pub fn blueprints(&self) -> &[Blueprint] {
+        &self.blueprints
+    }
+}
+
+impl Solution for Day19 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            blueprints: parse_lines(input)?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        let blueprints = &self.blueprints;
+        trace!("{:?}", blueprints);
+        debug!("{:?}", blueprints.len());
+
+        // each blueprint is searched on its own, so they can share the CPUs, or
+        // this thread's share of them when `aoc run` already has a pool going
+        let geodes = pool::map(blueprints, pool::workers(), |b| work(b, 24));
+        let mut quality = 0;
+        for (b, max_geodes) in blueprints.iter().zip(geodes) {
+            debug!("{max_geodes:?}");
+            quality += b.id * max_geodes
+        }
+
+        quality.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let blueprints = &self.blueprints;
+        trace!("{:?}", blueprints);
+        debug!("{:?}", blueprints.len());
+
+        let geodes = pool::map(blueprints.iter().take(3), pool::workers(), |b| work(b, 32));
+        let mut max = 1;
+        for max_geodes in geodes {
+            debug!("{max_geodes:?}");
+            max *= max_geodes
+        }
+
+        max.into()
+    }
+}
+
+fn work(blueprint: &Blueprint, time: i32) -> i32 {
+    let sim = Simulation::new(blueprint, time);
+    let mut visited = HashSet::new();
+    let mut queue = vec![sim.clone()];
+
+    let max_ore = blueprint.ore_cost().max(
+        blueprint.clay_cost().max(
+            blueprint
+                .obsidian_ore_cost()
+                .max(blueprint.geode_ore_cost()),
+        ),
+    );
+    let max_clay = blueprint.obsidian_clay_cost();
+    let max_obsidian = blueprint.geode_obsidian_cost();
+
+    let mut best = 0;
+    while let Some(state) = queue.pop() {
+        if state.time == 0 {
+            best = best.max(state.geode);
+            continue;
+        }
+
+        if visited.contains(&state) {
+            continue;
+        }
+
+        visited.insert(state.clone());
+
+        let can_continue = state.ore_robots >= max_ore
+            && state.clay_robots >= max_clay
+            && state.obsidian_robots >= max_obsidian;
+        // let can_continue = (state.ore_robots >= blueprint.geode_ore_cost()
+        //     && state.obsidian_robots >= blueprint.geode_obsidian_cost())
+        //     && (state.ore_robots >= blueprint.obsidian_ore_cost()
+        //         && state.clay_robots >= blueprint.obsidian_clay_cost());
+        // && state.clay_robots >= max_clay;
+
+        if blueprint.enough_for_geoode_robot(state.ore, state.obsidian) {
+            let mut c_state = state.tick();
+            c_state.buy_geode_robot();
+            queue.push(c_state);
+            // if (state.ore_robots >= blueprint.geode_ore_cost()
+            //     || state.ore_robots + state.ore >= blueprint.geode_ore_cost())
+            //     && (state.obsidian_robots >= blueprint.geode_obsidian_cost()
+            //         || state.obsidian_robots + state.obsidian >= blueprint.geode_obsidian_cost())
+            // {
+            if can_continue {
+                continue;
+            }
+            // }
+        }
+
+        if blueprint.enough_for_obsidian_robot(state.ore, state.clay)
+            && state.obsidian_robots < max_obsidian
+        {
+            let mut c_state = state.tick();
+            c_state.buy_obsidian_robot();
+            queue.push(c_state);
+
+            // if (state.ore_robots >= blueprint.obsidian_ore_cost()
+            //     || state.ore_robots + state.ore >= blueprint.obsidian_ore_cost())
+            // && (state.clay_robots >= blueprint.obsidian_clay_cost()
+            //     || state.clay_robots + state.clay >= blueprint.obsidian_clay_cost())
+            // {
+            if can_continue {
+                continue;
+            }
+            // }
+        }
+
+        queue.push(state.tick());
+        if state.blueprint.enough_for_clay_robot(state.ore) && state.clay_robots < max_clay {
+            let mut c_state = state.tick();
+            c_state.buy_clay_robot();
+            queue.push(c_state);
+        }
+
+        if state.blueprint.enough_for_ore_robot(state.ore) && state.ore_robots < max_ore {
+            let mut c_state = state.tick();
+            c_state.buy_ore_robot();
+            queue.push(c_state);
+        }
+    }
+
+    best
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Simulation<'a> {
+    blueprint: &'a Blueprint,
+    time: i32,
+    ore: i32,
+    ore_robots: i32,
+    clay: i32,
+    clay_robots: i32,
+    obsidian: i32,
+    obsidian_robots: i32,
+    geode: i32,
+    geode_robots: i32,
+}
+
+impl<'a> Simulation<'a> {
+    fn new(blueprint: &'a Blueprint, time: i32) -> Self {
+        Self {
+            blueprint,
+            time,
+            ore: 0,
+            ore_robots: 1,
+            clay: 0,
+            clay_robots: 0,
+            obsidian: 0,
+            obsidian_robots: 0,
+            geode: 0,
+            geode_robots: 0,
+        }
+    }
+
+    // fn simulate(&mut self) {
+    //     for t in 0..24 {
+    //         self.tick();
+    //     }
+    // }
+
+    fn tick(&self) -> Self {
+        // if self
+        //     .blueprint
+        //     .enough_for_geoode_robot(self.ore, self.obsidian)
+        // {
+        //     self.buy_geode_robot()
+        // } else if self
+        //     .blueprint
+        //     .enough_for_obsidian_robot(self.ore, self.clay)
+        //     && self.obsidian < self.blueprint.obsidian_clay_cost()
+        // {
+        //     self.buy_obsidian_robot()
+        // } else if self.blueprint.enough_for_clay_robot(self.ore)
+        //     && self.clay < self.blueprint.clay_cost()
+        // {
+        //     self.buy_clay_robot();
+        // } else if self.blueprint.enough_for_ore_robot(self.ore) {
+        // }
+
+        let mut s = self.clone();
+        s.ore += self.ore_robots;
+        s.clay += self.clay_robots;
+        s.obsidian += self.obsidian_robots;
+        s.geode += self.geode_robots;
+
+        s.time -= 1;
+
+        s
+    }
+
+    fn buy_ore_robot(&mut self) {
+        self.ore -= self.blueprint.ore_cost();
+        self.ore_robots += 1;
+    }
+
+    fn buy_clay_robot(&mut self) {
+        self.ore -= self.blueprint.clay_cost();
+        self.clay_robots += 1;
+    }
+
+    fn buy_obsidian_robot(&mut self) {
+        let (ore, clay) = self.blueprint.obsidian_cost();
+        self.ore -= ore;
+        self.clay -= clay;
+        self.obsidian_robots += 1;
+    }
+
+    fn buy_geode_robot(&mut self) {
+        let (ore, obsidian) = self.blueprint.geode_cost();
+        self.ore -= ore;
+        self.obsidian -= obsidian;
+        self.geode_robots += 1;
+    }
+}
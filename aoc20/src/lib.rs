@@ -0,0 +1,100 @@
+use aoc_common::{debug, parse_field, trace, Answer, Result, Solution};
+
+pub struct Day20 {
+    numbers: Vec<i64>,
+}
+
+impl Solution for Day20 {
+    fn parse(input: &str) -> Result<Self> {
+        let numbers = input
+            .lines()
+            .enumerate()
+            .map(|(i, v)| parse_field(v, v, "a number").map_err(|e| e.at_line(i + 1)))
+            .collect::<std::result::Result<_, _>>()?;
+
+        Ok(Self { numbers })
+    }
+
+    fn part1(&self) -> Answer {
+        let numbers: Vec<(usize, i64)> = self.numbers.iter().copied().enumerate().collect();
+        // println!("{numbers:?}");
+
+        let mut mixer = numbers.clone();
+        for number in &numbers {
+            let n = mixer.iter().position(|s| s.0 == number.0).unwrap();
+            let cur = mixer.remove(n);
+            // going round the others any number of times lands in the same
+            // place, and a lone number has nowhere else to go
+            let len = mixer.len().max(1) as i64;
+            let idx = (n as i64 + cur.1.rem_euclid(len)) % len;
+            mixer.insert(idx as usize, cur);
+        }
+
+        trace!("{mixer:?}");
+        let zeroth = mixer.iter().position(|(_, v)| *v == 0);
+        if let Some(n) = zeroth {
+            debug!("Oth: {}", n);
+            let first = (n + 1000) % mixer.len();
+            let next = mixer[first].1;
+            debug!("{next}");
+
+            let second = (n + 2000) % mixer.len();
+            let next2 = mixer[second].1;
+            debug!("{next2}");
+
+            let third = (n + 3000) % mixer.len();
+            let next3 = mixer[third].1;
+            debug!("{next3}");
+
+            let sum = next.checked_add(next2).and_then(|s| s.checked_add(next3));
+            return sum.map_or(Answer::Unsolved, Answer::from);
+        }
+
+        Answer::Unsolved
+    }
+
+    fn part2(&self) -> Answer {
+        let Some(numbers) = self
+            .numbers
+            .iter()
+            .map(|v| v.checked_mul(811589153))
+            .collect::<Option<Vec<i64>>>()
+        else {
+            return Answer::Unsolved;
+        };
+        let numbers: Vec<(usize, i64)> = numbers.into_iter().enumerate().collect();
+
+        let mut mixer = numbers.clone();
+        for _r in 0..10 {
+            for number in &numbers {
+                let n = mixer.iter().position(|s| s.0 == number.0).unwrap();
+                let cur = mixer.remove(n);
+                let len = mixer.len().max(1) as i64;
+                let idx = (n as i64 + cur.1.rem_euclid(len)) % len;
+                mixer.insert(idx as usize, cur);
+            }
+        }
+
+        trace!("{mixer:?}");
+        let zeroth = mixer.iter().position(|(_, v)| *v == 0);
+        if let Some(n) = zeroth {
+            debug!("Oth: {}", n);
+            let first = (n + 1000) % mixer.len();
+            let next = mixer[first].1;
+            debug!("{next}");
+
+            let second = (n + 2000) % mixer.len();
+            let next2 = mixer[second].1;
+            debug!("{next2}");
+
+            let third = (n + 3000) % mixer.len();
+            let next3 = mixer[third].1;
+            debug!("{next3}");
+
+            let sum = next.checked_add(next2).and_then(|s| s.checked_add(next3));
+            return sum.map_or(Answer::Unsolved, Answer::from);
+        }
+
+        Answer::Unsolved
+    }
+}
@@ -0,0 +1,268 @@
+use aoc_common::{debug, parse_field, parse_lines, trace, Answer, ParseError, Result, Solution};
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+#[derive(Debug, Clone, Default)]
+enum Operation {
+    #[default]
+    Noop,
+    Add(String, String),
+    Sub(String, String),
+    Mul(String, String),
+    Div(String, String),
+}
+
+#[derive(Debug, Clone, Default)]
+struct Monkey {
+    name: String,
+    number: i64,
+    operation: Operation,
+}
+
+// root: pppw + sjmn
+// dbpl: 5
+impl FromStr for Monkey {
+    type Err = ParseError;
+
+    fn from_str(item: &str) -> std::result::Result<Self, Self::Err> {
+        let (name, job) = item
+            .split_once(": ")
+            .ok_or_else(|| ParseError::new(item, item, "<name>: <job>"))?;
+        let parts: Vec<&str> = job.split_whitespace().collect();
+        match parts[..] {
+            [number] => Ok(Self {
+                name: name.to_string(),
+                number: parse_field(item, number, "a number or an operation")?,
+                ..Default::default()
+            }),
+            [l, op, r] => {
+                let (l, r) = (l.to_string(), r.to_string());
+                let operation = match op {
+                    "+" => Operation::Add(l, r),
+                    "-" => Operation::Sub(l, r),
+                    "*" => Operation::Mul(l, r),
+                    "/" => Operation::Div(l, r),
+                    _ => return Err(ParseError::new(item, op, "an operator, + - * or /")),
+                };
+                Ok(Self {
+                    name: name.to_string(),
+                    operation,
+                    ..Default::default()
+                })
+            }
+            _ => Err(ParseError::new(item, job, "a number or <name> <op> <name>")),
+        }
+    }
+}
+
+pub struct Day21 {
+    monkeys: HashMap<String, Monkey>,
+}
+
+impl Solution for Day21 {
+    fn parse(input: &str) -> Result<Self> {
+        let list: Vec<Monkey> = parse_lines(input)?;
+        let monkeys: HashMap<String, Monkey> =
+            list.iter().map(|m| (m.name.clone(), m.clone())).collect();
+
+        for key in ["root", "humn"] {
+            if !monkeys.contains_key(key) {
+                let expected = format!("a monkey named {key}");
+                return Err(ParseError::missing(input, expected).into());
+            }
+        }
+
+        for (i, (m, l)) in list.iter().zip(input.lines()).enumerate() {
+            let operands = match &m.operation {
+                Operation::Add(l, r)
+                | Operation::Sub(l, r)
+                | Operation::Mul(l, r)
+                | Operation::Div(l, r) => vec![l, r],
+                Operation::Noop => vec![],
+            };
+
+            if let Some(name) = operands.into_iter().find(|n| !monkeys.contains_key(*n)) {
+                let found = &l[l.rfind(name.as_str()).unwrap_or(0)..][..name.len()];
+                let err = ParseError::new(l, found, "the name of a monkey");
+                return Err(err.at_line(i + 1).into());
+            }
+        }
+
+        if let Operation::Noop = monkeys["root"].operation {
+            let l = input
+                .lines()
+                .find(|l| l.starts_with("root: "))
+                .unwrap_or(input);
+            return Err(ParseError::new(l, l, "root to wait on two monkeys").into());
+        }
+
+        // a monkey waiting on its own number would never shout it
+        let mut done = HashSet::new();
+        for name in monkeys.keys() {
+            if let Some(name) = find_cycle(&monkeys, name, &mut vec![], &mut done) {
+                let l = input.lines().find(|l| l.starts_with(&format!("{name}: ")));
+                let l = l.unwrap_or(input);
+                return Err(ParseError::new(l, l, "a monkey that doesn't wait on itself").into());
+            }
+        }
+
+        Ok(Self { monkeys })
+    }
+
+    fn part1(&self) -> Answer {
+        let monkeys = &self.monkeys;
+        trace!("{monkeys:?}");
+
+        evaluate(monkeys, "root").map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(&self) -> Answer {
+        let mut monkeys = self.monkeys.clone();
+        trace!("{monkeys:?}");
+
+        // let mut human_attempt = 1_000_000_000_000_00i64;
+        let mut human_attempt = 1;
+
+        // root only compares its two monkeys, whatever its job says
+        let (left, right) = match &monkeys["root"].operation {
+            Operation::Add(l, r)
+            | Operation::Sub(l, r)
+            | Operation::Mul(l, r)
+            | Operation::Div(l, r) => (l.clone(), r.clone()),
+            Operation::Noop => return Answer::Unsolved,
+        };
+
+        // the search closes in on the real inputs in a few hundred steps; a
+        // tree it can't close in on gets no answer rather than a hang
+        for i in 1..=MAX_ATTEMPTS {
+            if let Some(humn) = monkeys.get_mut("humn") {
+                humn.number = human_attempt;
+            }
+
+            let (Some(l_value), Some(r_value)) =
+                (evaluate(&monkeys, &left), evaluate(&monkeys, &right))
+            else {
+                debug!("humn {human_attempt}: root's monkeys overflow or divide by zero");
+                return Answer::Unsolved;
+            };
+
+            if l_value == r_value {
+                debug!("{l_value}");
+                debug!("{r_value}");
+                debug!("Iterations: {i}");
+                return evaluate(&monkeys, "humn").map_or(Answer::Unsolved, Answer::from);
+            }
+
+            let step = l_value.abs_diff(r_value) / 500 + 1;
+            match i64::try_from(step)
+                .ok()
+                .and_then(|s| human_attempt.checked_add(s))
+            {
+                Some(next) => human_attempt = next,
+                None => return Answer::Unsolved,
+            }
+        }
+
+        debug!("no humn found in {MAX_ATTEMPTS} attempts");
+        Answer::Unsolved
+    }
+}
+
+/// How many numbers part 2 tries for humn before giving up.
+const MAX_ATTEMPTS: usize = 100_000;
+
+/// The number `monkey` shouts, or `None` when working it out overflows or
+/// divides by zero.
+fn evaluate(monkeys: &HashMap<String, Monkey>, monkey: &str) -> Option<i64> {
+    let m = monkeys.get(monkey)?;
+    match &m.operation {
+        Operation::Noop => Some(m.number),
+        Operation::Add(left_name, right_name) => {
+            let left = evaluate(monkeys, left_name)?;
+            let right = evaluate(monkeys, right_name)?;
+            left.checked_add(right)
+        }
+        Operation::Sub(left_name, right_name) => {
+            let left = evaluate(monkeys, left_name)?;
+            let right = evaluate(monkeys, right_name)?;
+            left.checked_sub(right)
+        }
+        Operation::Mul(left_name, right_name) => {
+            let left = evaluate(monkeys, left_name)?;
+            let right = evaluate(monkeys, right_name)?;
+            left.checked_mul(right)
+        }
+        Operation::Div(left_name, right_name) => {
+            let left = evaluate(monkeys, left_name)?;
+            let right = evaluate(monkeys, right_name)?;
+            left.checked_div(right)
+        }
+    }
+}
+
+/// A monkey on a loop through `name`, if its job leads back to itself.
+/// `path` holds the monkeys being followed, and `done` those known to be clear.
+fn find_cycle<'a>(
+    monkeys: &'a HashMap<String, Monkey>,
+    name: &'a str,
+    path: &mut Vec<&'a str>,
+    done: &mut HashSet<&'a str>,
+) -> Option<&'a str> {
+    if done.contains(name) {
+        return None;
+    }
+    if path.contains(&name) {
+        return Some(name);
+    }
+
+    path.push(name);
+    if let Some(m) = monkeys.get(name) {
+        if let Operation::Add(l, r)
+        | Operation::Sub(l, r)
+        | Operation::Mul(l, r)
+        | Operation::Div(l, r) = &m.operation
+        {
+            for next in [l, r] {
+                if let Some(found) = find_cycle(monkeys, next, path, done) {
+                    return Some(found);
+                }
+            }
+        }
+    }
+    path.pop();
+    done.insert(name);
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unexpected_trees() {
+        // root may compare with any operator
+        let day = Day21::parse("root: humn * two\nhumn: 1\ntwo: 2\n").unwrap();
+        assert_eq!((day.part1(), day.part2()), (2.into(), 2.into()));
+
+        let err = Day21::parse("root: 5\nhumn: 1\n").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "line 1, column 1: expected root to wait on two monkeys, found \"root: 5\""
+        );
+        let err = Day21::parse("root: humn + aaaa\nhumn: 1\naaaa: aaaa + humn\n")
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("doesn't wait on itself"));
+
+        // dividing by zero, or a humn that can never balance root
+        let day = Day21::parse("root: humn + zero\nhumn: 1\nzero: humn / nil\nnil: 0\n").unwrap();
+        assert_eq!(
+            (day.part1(), day.part2()),
+            (Answer::Unsolved, Answer::Unsolved)
+        );
+        let day = Day21::parse("root: humn + more\nhumn: 5\nmore: humn + one\none: 1\n").unwrap();
+        assert_eq!(day.part2(), Answer::Unsolved);
+    }
+}
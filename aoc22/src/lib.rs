@@ -0,0 +1,407 @@
+use aoc_common::{
+    debug,
+    geometry::Direction,
+    grid::{Coord, SparseGrid},
+    parse_field,
+    visualize::{Colour, Frame, Screen, Visualize},
+    Answer, ParseError, Result, Solution,
+};
+use core::fmt;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, Default)]
+enum Cell {
+    #[default]
+    Empty,
+    Open,
+    Wall,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Cell::Empty => write!(f, " "),
+            Cell::Open => write!(f, "."),
+            Cell::Wall => write!(f, "#"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Bounds {
+    min_r: i64,
+    min_c: i64,
+    max_r: i64,
+    max_c: i64,
+}
+
+#[derive(Debug, Default)]
+struct Board {
+    board: SparseGrid<Cell>,
+    bounds: HashMap<Coord, Bounds>,
+}
+
+#[derive(Debug, Clone, Default)]
+enum Path {
+    #[default]
+    Noop,
+    Move(usize),
+    Dir(Turn),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Turn {
+    Left,
+    Right,
+}
+
+impl Turn {
+    fn apply(self, d: Direction) -> Direction {
+        match self {
+            Turn::Left => d.turn_left(),
+            Turn::Right => d.turn_right(),
+        }
+    }
+}
+
+impl TryFrom<char> for Turn {
+    type Error = ParseError;
+
+    fn try_from(item: char) -> std::result::Result<Self, Self::Error> {
+        match item {
+            'L' => Ok(Self::Left),
+            'R' => Ok(Self::Right),
+            _ => Err(ParseError::new("", "", "a turn, L or R")),
+        }
+    }
+}
+
+pub struct Day22 {
+    board: Board,
+    moves: Vec<Path>,
+}
+
+impl Solution for Day22 {
+    fn parse(input: &str) -> Result<Self> {
+        let (b, path) = input
+            .split_once("\n\n")
+            .ok_or("expected the board followed by a blank line and the path")?;
+        let mut board = Board {
+            board: SparseGrid::parse(b, ' ', "a tile, '.', '#' or ' '", |c| match c {
+                '.' => Some(Cell::Open),
+                '#' => Some(Cell::Wall),
+                _ => None,
+            })?,
+            ..Default::default()
+        };
+
+        // process to get min/max for each Point in Board
+        let (top_left, bottom_right) = board
+            .board
+            .bounds()
+            .ok_or_else(|| ParseError::missing(b, "a board of '.' and '#' tiles"))?;
+        let ends = |cells: Vec<Coord>, axis: fn(&Coord) -> i64| {
+            cells
+                .first()
+                .zip(cells.last())
+                .map(|(a, z)| (axis(a), axis(z)))
+        };
+        let rows: HashMap<i64, (i64, i64)> = (top_left.y..=bottom_right.y)
+            .filter_map(|y| {
+                let cells = board.board.row(y).map(|(k, _)| k).collect();
+                Some((y, ends(cells, |k| k.x)?))
+            })
+            .collect();
+        let columns: HashMap<i64, (i64, i64)> = (top_left.x..=bottom_right.x)
+            .filter_map(|x| {
+                let cells = board.board.column(x).map(|(k, _)| k).collect();
+                Some((x, ends(cells, |k| k.y)?))
+            })
+            .collect();
+
+        for p in board.board.positions() {
+            let (min_r, max_r) = columns[&p.x];
+            let (min_c, max_c) = rows[&p.y];
+
+            board.bounds.insert(
+                p,
+                Bounds {
+                    min_r,
+                    max_r,
+                    min_c,
+                    max_c,
+                },
+            );
+        }
+
+        // the path is on the line after the board and the blank line
+        let path_line = b.lines().count() + 2;
+        let path = path.trim();
+        let mut start = 0;
+        let mut moves = vec![];
+        for (pos, p) in path.char_indices() {
+            if p.is_ascii_digit() {
+                continue;
+            }
+
+            let steps = parse_field(path, &path[start..pos], "a number of tiles to move")
+                .map_err(|e| e.at_line(path_line))?;
+            let turn = Turn::try_from(p).map_err(|e| {
+                let found = &path[pos..pos + p.len_utf8()];
+                ParseError::new(path, found, e.expected).at_line(path_line)
+            })?;
+            moves.push(Path::Move(steps));
+            moves.push(Path::Dir(turn));
+            start = pos + p.len_utf8();
+        }
+
+        if start < path.len() {
+            let steps = parse_field(path, &path[start..], "a number of tiles to move")
+                .map_err(|e| e.at_line(path_line))?;
+            moves.push(Path::Move(steps));
+        }
+
+        Ok(Self { board, moves })
+    }
+
+    fn part1(&self) -> Answer {
+        // println!("{moves:?}");
+        let board = &self.board;
+        let Some(first) = board.board.row(0).next().map(|(k, _)| k.x) else {
+            return Answer::Unsolved;
+        };
+
+        debug!("First: (0, {first})");
+
+        let mut cur = Direction::Right;
+
+        let mut history = HashSet::new();
+        let mut loc = Coord::new(first, 0);
+        for m in &self.moves {
+            match m {
+                Path::Move(s) => {
+                    // coming back round to the start of the move repeats it,
+                    // so only the steps left over from whole laps count
+                    let (start, mut left, mut taken) = (loc, *s, 0);
+                    while left > 0 {
+                        left -= 1;
+                        taken += 1;
+                        let mut np = loc + cur.offset();
+                        // check bounds
+                        if let Some(bounds) = board.bounds.get(&loc) {
+                            if np.y < bounds.min_r {
+                                np.y = bounds.max_r;
+                            }
+
+                            if np.y > bounds.max_r {
+                                np.y = bounds.min_r;
+                            }
+
+                            if np.x < bounds.min_c {
+                                np.x = bounds.max_c;
+                            }
+
+                            if np.x > bounds.max_c {
+                                np.x = bounds.min_c;
+                            }
+                            if let Some(Cell::Wall) = board.board.get(np) {
+                                break;
+                            }
+
+                            loc = np;
+                            history.insert(loc);
+                        }
+                        if loc == start {
+                            left %= taken;
+                        }
+                    }
+                }
+                Path::Dir(t) => {
+                    cur = t.apply(cur);
+                }
+                _ => {}
+            }
+        }
+
+        // println!("{board:?}");
+        // _print_board(&board, &history);
+        // println!("History: {history:#?}");
+        debug!("Facing: {cur:?}");
+        debug!("Loc: {loc:?}");
+
+        let facing_value = match cur {
+            Direction::Right => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Up => 3,
+        };
+
+        let password = (1000 * (loc.y + 1)) + (4 * (loc.x + 1)) + facing_value;
+        password.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let Some((loc, cur)) = self.walk_cube(|_, _, _| ()) else {
+            debug!("the board doesn't fold like the real input's");
+            return Answer::Unsolved;
+        };
+
+        debug!("Facing: {cur:?}");
+        debug!("Loc: {loc:?}");
+
+        let facing_value = match cur {
+            Direction::Right => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Up => 3,
+        };
+
+        let password = (1000 * (loc.y + 1)) + (4 * (loc.x + 1)) + facing_value;
+        password.into()
+    }
+}
+
+impl Day22 {
+    /// Follows the path around the board folded into a cube, calling `moved`
+    /// with the instruction's index, the position and the facing after every
+    /// step and turn. Returns where it ends, or `None` if the path leaves a
+    /// board that isn't laid out like the real input's net.
+    fn walk_cube(
+        &self,
+        mut moved: impl FnMut(usize, Coord, Direction),
+    ) -> Option<(Coord, Direction)> {
+        let board = &self.board;
+        let first = board.board.row(0).next().map(|(k, _)| k.x)?;
+
+        debug!("First: (0, {first})");
+
+        let mut cur = Direction::Right;
+        let mut loc = Coord::new(first, 0);
+        for (i, m) in self.moves.iter().enumerate() {
+            match m {
+                Path::Move(s) => {
+                    // as in part 1, but a lap of the cube can change direction
+                    let (start, mut left, mut taken) = ((loc, cur), *s, 0);
+                    while left > 0 {
+                        left -= 1;
+                        taken += 1;
+                        let np = loc + cur.offset();
+                        // check bounds
+                        if let Some(vp) = board.board.get(np) {
+                            if let Cell::Wall = vp {
+                                break;
+                            }
+                            loc = np;
+                        } else {
+                            let (_, tp, nd) = wrap_cube(loc, cur)?;
+                            if let Cell::Wall = board.board.get(tp)? {
+                                break;
+                            }
+
+                            loc = tp;
+                            cur = nd;
+                        }
+                        moved(i, loc, cur);
+                        if (loc, cur) == start {
+                            left %= taken;
+                        }
+                    }
+                }
+                Path::Dir(t) => {
+                    cur = t.apply(cur);
+                    moved(i, loc, cur);
+                }
+                _ => {}
+            }
+        }
+
+        Some((loc, cur))
+    }
+}
+
+impl Visualize for Day22 {
+    fn visualize(&self, screen: &mut Screen) {
+        let (min, max) = self.board.board.bounds().unwrap();
+        let mut frame = Frame::new(min, max);
+        for (p, c) in self.board.board.iter() {
+            frame.set(p, c.to_string().chars().next().unwrap(), Colour::Grey);
+        }
+
+        let arrow = |d: Direction| d.to_string().chars().next().unwrap();
+        let mut last = None;
+        let mut shown = 0;
+        self.walk_cube(|i, loc, d| {
+            // a frame for each instruction, drawn once the next one starts
+            if i != shown {
+                frame.set_caption(format!("instruction {i} of {}", self.moves.len()));
+                screen.show(&frame);
+                shown = i;
+            }
+
+            if let Some((p, d)) = last {
+                frame.set(p, arrow(d), Colour::Cyan);
+            }
+            frame.set(loc, arrow(d), Colour::Red);
+            last = Some((loc, d));
+        });
+    }
+}
+
+const SIZE: i64 = 50;
+
+/// Where stepping off the edge of a face goes, for the real input's net, or
+/// `None` off any other.
+fn wrap_cube(from: Coord, d: Direction) -> Option<(usize, Coord, Direction)> {
+    let side_idx = (from.y / SIZE) * 3 + from.x / SIZE;
+    // println!("side_idx({from:?}): {side_idx}");
+    let sides = [0, 2, 3, 0, 1, 0, 4, 5, 0, 6];
+
+    let side = *sides.get(usize::try_from(side_idx).ok()?)?;
+
+    // let row_offset = from.0 % SIZE;
+    // let col_offset = from.1 % SIZE;
+    let row = from.y;
+    let col = from.x;
+
+    // the faces are laid out as (row, col) below
+    let (side, (row, col), d) = match (side, d) {
+        (1, Direction::Right) => (3, (SIZE - 1, row + 50), Direction::Up),
+        (1, Direction::Left) => (4, ((SIZE * 2), row - SIZE), Direction::Down),
+        (2, Direction::Up) => (6, (col + 100, 0), Direction::Right),
+        (2, Direction::Left) => (4, (149 - row, 0), Direction::Right),
+        (3, Direction::Up) => (6, ((SIZE * 4) - 1, col - 100), Direction::Up),
+        (3, Direction::Right) => (5, (149 - row, (SIZE * 2) - 1), Direction::Left),
+        (3, Direction::Down) => (1, (col - SIZE, (SIZE * 2) - 1), Direction::Left),
+        (4, Direction::Up) => (1, (col + SIZE, SIZE), Direction::Right),
+        // (4, Direction::Right) => (5, (from.0, (SIZE + col_offset)), Direction::Right),
+        (4, Direction::Left) => (2, (149 - row, SIZE), Direction::Right),
+        (5, Direction::Right) => (3, (149 - row, (SIZE * 3) - 1), Direction::Left),
+        (5, Direction::Down) => (6, (col + 100, SIZE - 1), Direction::Left),
+        (6, Direction::Right) => (5, (SIZE * 3 - 1, row - 100), Direction::Up),
+        (6, Direction::Down) => (3, (0, col + 100), Direction::Down),
+        (6, Direction::Left) => (2, (0, row - 100), Direction::Down),
+
+        // (1, Direction::Right) => (3, (SIZE - 1, SIZE * 2 + row_offset), Direction::Up),
+        // (1, Direction::Left) => (4, ((SIZE * 2), row_offset), Direction::Down),
+        // (2, Direction::Up) => (6, (SIZE * 3 + col_offset, 0), Direction::Right),
+        // (2, Direction::Left) => (4, (SIZE * 2 + row_offset, 0), Direction::Right),
+        // (3, Direction::Up) => (6, ((SIZE * 4) - 1, col_offset), Direction::Up),
+        // (3, Direction::Right) => (
+        //     5,
+        //     ((SIZE * 2) + row_offset, (SIZE * 2) - 1),
+        //     Direction::Left,
+        // ),
+        // (3, Direction::Down) => (1, (SIZE + col_offset, (SIZE * 2) - 1), Direction::Left),
+        // (4, Direction::Up) => (1, (SIZE + col_offset, (SIZE)), Direction::Right),
+        // // (4, Direction::Right) => (5, (from.0, (SIZE + col_offset)), Direction::Right),
+        // (4, Direction::Left) => (2, (row_offset, SIZE), Direction::Right),
+        // (5, Direction::Right) => (3, (row_offset, (SIZE * 3) - 1), Direction::Left),
+        // (5, Direction::Down) => (6, ((SIZE * 3) + col_offset, SIZE - 1), Direction::Left),
+        // (6, Direction::Right) => (5, (SIZE * 3 - 1, (SIZE + row_offset)), Direction::Up),
+        // (6, Direction::Down) => (3, (0, SIZE * 2 + col_offset), Direction::Down),
+        // (6, Direction::Left) => (2, (0, SIZE + col_offset), Direction::Down),
+        _ => return None,
+    };
+
+    Some((side, Coord::new(col, row), d))
+}
@@ -0,0 +1,189 @@
+use aoc_common::{
+    debug,
+    geometry::Direction,
+    grid::{Coord, SparseGrid},
+    visualize::{Colour, Frame, Screen, Visualize},
+    Answer, ParseError, Result, Solution,
+};
+use std::{collections::HashMap, fmt};
+
+type Point = Coord;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Elf;
+
+impl fmt::Display for Elf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#")
+    }
+}
+
+/// The three steps an elf looks along before moving in direction `d`: straight
+/// ahead and the diagonals either side of it.
+fn adjacent(d: Direction) -> [Point; 3] {
+    let ahead: Point = d.offset();
+    [
+        ahead,
+        ahead + d.turn_left().offset(),
+        ahead + d.turn_right().offset(),
+    ]
+}
+
+pub struct Day23 {
+    elves: SparseGrid<Elf>,
+}
+
+impl Solution for Day23 {
+    fn parse(input: &str) -> Result<Self> {
+        let elves = SparseGrid::parse(input, '.', "an elf '#' or ground '.'", |c| {
+            (c == '#').then_some(Elf)
+        })?;
+
+        if elves.is_empty() {
+            return Err(ParseError::missing(input, "at least one elf '#'").into());
+        }
+
+        Ok(Self { elves })
+    }
+
+    fn part1(&self) -> Answer {
+        let (board, _) = play(&self.elves, 10, |_, _| ());
+
+        let (min, max) = board.bounds().unwrap();
+        let (min_r, min_c, max_r, max_c) = (min.y, min.x, max.y, max.x);
+        // println!("Min: ({min_r}, {min_c})");
+        // println!("Max: ({max_r}, {max_c})");
+
+        let area = (max_r - min_r + 1) * (max_c - min_c + 1);
+        let elves: i64 = board.len() as i64;
+        let total = area - elves;
+        debug!("{total}");
+        let mut count = 0;
+        for r in min_r..=max_r {
+            for c in min_c..=max_c {
+                if !board.contains(Point::new(c, r)) {
+                    count += 1;
+                }
+            }
+        }
+
+        count.into()
+    }
+
+    fn part2(&self) -> Answer {
+        let (_, rounds) = play(&self.elves, -1, |_, _| ());
+        rounds.into()
+    }
+}
+
+/// Plays `iterations` rounds, or until no elf moves when it is -1, calling
+/// `round_done` with the board after each round.
+fn play(
+    elves: &SparseGrid<Elf>,
+    iterations: i32,
+    mut round_done: impl FnMut(&SparseGrid<Elf>, i32),
+) -> (SparseGrid<Elf>, i32) {
+    let mut board = elves.clone();
+
+    // print_board(&board);
+
+    // propose moves, north, south, west then east
+    let mut directions = Direction::ALL.to_vec();
+
+    let mut round = 0;
+    loop {
+        let mut proposals: HashMap<Point, Point> = HashMap::new();
+        let mut proposal_counts: HashMap<Point, i32> = HashMap::new();
+
+        for elf in board.positions() {
+            let mut can_move = false;
+            for dir in &directions {
+                let adjacent = adjacent(*dir);
+                for a in adjacent {
+                    let p = elf + a;
+                    let available = board.contains(p);
+
+                    if available {
+                        can_move = true;
+                        break;
+                    }
+                }
+
+                if can_move {
+                    break;
+                }
+            }
+
+            if !can_move {
+                continue;
+            }
+
+            for dir in &directions {
+                let adjacent = adjacent(*dir);
+                let mut able_to_move = true;
+                for a in adjacent {
+                    let p = elf + a;
+                    let available = board.contains(p);
+                    if available {
+                        able_to_move = false;
+                        break;
+                    }
+                }
+
+                if able_to_move {
+                    let p = elf + dir.offset();
+                    proposals.entry(elf).or_insert(p);
+                    proposal_counts
+                        .entry(p)
+                        .and_modify(|f| *f += 1)
+                        .or_insert(1);
+                    break;
+                }
+            }
+        }
+
+        if (iterations == -1 && proposals.is_empty()) || iterations == round {
+            break;
+        }
+
+        let front = directions.remove(0);
+        directions.insert(directions.len(), front);
+
+        // move if possible
+        for (k, p) in proposals.iter() {
+            if board.contains(*k) {
+                if let Some(c) = proposal_counts.get(p) {
+                    if *c == 1 {
+                        board.remove(*k);
+                        board.insert(*p, Elf);
+                    }
+                }
+            }
+        }
+
+        round += 1;
+        round_done(&board, round);
+    }
+
+    (board, round + 1)
+}
+
+impl Visualize for Day23 {
+    fn visualize(&self, screen: &mut Screen) {
+        // the view only ever grows, so it doesn't jump about as the elves
+        // at the edges move in and out
+        let (mut min, mut max) = self.elves.bounds().unwrap();
+        play(&self.elves, -1, |board, round| {
+            let (lo, hi) = board.bounds().unwrap();
+            min = Point::new(min.x.min(lo.x), min.y.min(lo.y));
+            max = Point::new(max.x.max(hi.x), max.y.max(hi.y));
+
+            let mut frame = Frame::new(min, max);
+            frame.set_caption(format!("round {round}"));
+            for p in board.positions() {
+                frame.set(p, '#', Colour::Green);
+            }
+            screen.show(&frame);
+        });
+    }
+}
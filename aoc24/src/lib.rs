@@ -0,0 +1,264 @@
+//! Day 24: Blizzard Basin. Breadth first search through a [`Maze`] whose
+//! blizzards move every minute.
+use aoc_common::{
+    debug,
+    geometry::Direction,
+    grid::{Coord, Grid, Pos},
+    math::lcm,
+    search::{Found, Search},
+    visualize::{Colour, Frame, Screen, Visualize},
+    Answer, ParseError, Result, Solution,
+};
+use std::{fmt, str::FromStr};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub enum Cell {
+    #[default]
+    Noop,
+    Wall,
+    Space,
+    Blizzard(Vec<Direction>),
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Cell::Noop => write!(f, " "),
+            Cell::Space => write!(f, "."),
+            Cell::Wall => write!(f, "#"),
+            Cell::Blizzard(ref d) => {
+                if d.len() > 1 {
+                    write!(f, "{}", d.len())
+                } else {
+                    write!(f, "{}", d[0])
+                }
+            }
+        }
+    }
+}
+
+/// The valley: its walls, and the blizzards as they are at the start.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Maze(Grid<Cell>);
+
+impl FromStr for Maze {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> std::result::Result<Self, Self::Err> {
+        let board = Grid::parse(input, "a wall, space or blizzard", |c| match c {
+            '#' => Some(Cell::Wall),
+            '.' => Some(Cell::Space),
+            '>' => Some(Cell::Blizzard(vec![Direction::Right])),
+            '<' => Some(Cell::Blizzard(vec![Direction::Left])),
+            '^' => Some(Cell::Blizzard(vec![Direction::Up])),
+            'v' => Some(Cell::Blizzard(vec![Direction::Down])),
+            _ => None,
+        })?;
+
+        if board.height() < 3 || board.width() < 3 {
+            return Err(ParseError::missing(
+                input,
+                "a valley with walls above and below",
+            ));
+        }
+
+        // the start and end are the gaps in the top and bottom walls
+        let lines: Vec<&str> = input.lines().collect();
+        for r in [0, board.height() - 1] {
+            if !board.row(r).any(|(_, c)| *c == Cell::Space) {
+                let err = ParseError::new(lines[r], lines[r], "a wall with a gap '.' in it");
+                return Err(err.at_line(r + 1));
+            }
+        }
+
+        Ok(Maze(board))
+    }
+}
+
+impl Maze {
+    pub fn get(&self, p: Pos) -> &Cell {
+        &self.0[p]
+    }
+
+    /// The gap in the wall along row `y`.
+    fn gap(&self, y: usize) -> Pos {
+        self.0
+            .row(y)
+            .find(|(_, c)| **c == Cell::Space)
+            .map(|(p, _)| p)
+            .unwrap()
+    }
+
+    /// Where the expedition sets off, the gap in the top wall.
+    pub fn start(&self) -> Pos {
+        self.gap(0)
+    }
+
+    /// The gap in the bottom wall.
+    pub fn end(&self) -> Pos {
+        self.gap(self.0.height() - 1)
+    }
+
+    /// The width and height of the valley inside the walls.
+    fn inner(&self) -> (usize, usize) {
+        (self.0.width() - 2, self.0.height() - 2)
+    }
+
+    /// The blizzards covering `p` after `t` minutes. Blizzards wrap around
+    /// inside the walls, so each one on `p` now started `t` cells upwind of it.
+    fn blizzards_at(&self, p: Pos, t: usize) -> impl Iterator<Item = Direction> + '_ {
+        let (w, h) = self.inner();
+        // the walls, and the start and end rows, never have blizzards
+        let inside = (1..=w).contains(&p.x) && (1..=h).contains(&p.y);
+
+        let (x, y) = (p.x.wrapping_sub(1), p.y.wrapping_sub(1));
+        let starts = inside.then(|| {
+            [
+                (Direction::Right, ((x + w - t % w) % w, y)),
+                (Direction::Left, ((x + t) % w, y)),
+                (Direction::Down, (x, (y + h - t % h) % h)),
+                (Direction::Up, (x, (y + t) % h)),
+            ]
+        });
+
+        starts.into_iter().flatten().filter_map(move |(d, (x, y))| {
+            match self.get(Pos::new(x + 1, y + 1)) {
+                Cell::Blizzard(v) if v.contains(&d) => Some(d),
+                _ => None,
+            }
+        })
+    }
+
+    /// Whether any blizzard covers `p` after `t` minutes.
+    pub fn blizzard_at(&self, p: Pos, t: usize) -> bool {
+        self.blizzards_at(p, t).next().is_some()
+    }
+
+    /// The earliest time the expedition can reach `to` when setting off from
+    /// `from` at time `start`.
+    pub fn crossing(&self, from: Pos, to: Pos, start: usize) -> Option<usize> {
+        let found = self.route(from, to, start, false)?;
+        Some(start + found.cost)
+    }
+
+    /// The quickest way from `from` to `to` setting off at time `start`,
+    /// with the positions along it when `path` is set.
+    fn route(
+        &self,
+        from: Pos,
+        to: Pos,
+        start: usize,
+        path: bool,
+    ) -> Option<Found<(Pos, usize), usize>> {
+        // the blizzards are back where they began every `period` minutes, so
+        // that is all of the time a state needs to remember
+        let (w, h) = self.inner();
+        let period = lcm(w, h);
+
+        let mut search = Search::from((from, start % period));
+        if path {
+            search = search.with_path();
+        }
+        search.bfs(
+            |&(p, t)| {
+                let t = (t + 1) % period;
+                // waiting where we are is a move too
+                self.0
+                    .neighbours4(p)
+                    .chain([p])
+                    .filter(move |&n| *self.get(n) != Cell::Wall && !self.blizzard_at(n, t))
+                    .map(move |n| (n, t))
+            },
+            |&(p, _)| p == to,
+        )
+    }
+}
+
+pub struct Day24 {
+    maze: Maze,
+}
+
+impl Day24 {
+    pub fn maze(&self) -> &Maze {
+        &self.maze
+    }
+}
+
+impl Solution for Day24 {
+    fn parse(input: &str) -> Result<Self> {
+        Ok(Self {
+            maze: input.parse()?,
+        })
+    }
+
+    fn part1(&self) -> Answer {
+        let maze = &self.maze;
+        let start = maze.start();
+        let end = maze.end();
+
+        maze.crossing(start, end, 0)
+            .map_or(Answer::Unsolved, Answer::from)
+    }
+
+    fn part2(&self) -> Answer {
+        let maze = &self.maze;
+        let start = maze.start();
+        let end = maze.end();
+
+        // there, back for the snacks, and there again
+        let mut time = 0;
+        for (goal_idx, (from, to)) in [(start, end), (end, start), (start, end)]
+            .into_iter()
+            .enumerate()
+        {
+            let Some(arrival) = maze.crossing(from, to, time) else {
+                return Answer::Unsolved;
+            };
+            debug!("Goal {goal_idx}: {arrival}");
+            time = arrival;
+        }
+
+        time.into()
+    }
+}
+
+impl Visualize for Day24 {
+    fn visualize(&self, screen: &mut Screen) {
+        let maze = &self.maze;
+        let start = maze.start();
+        let end = maze.end();
+        let corner = Coord::new(maze.0.width() as i64 - 1, maze.0.height() as i64 - 1);
+        let coord = |p: Pos| Coord::new(p.x as i64, p.y as i64);
+
+        let mut time = 0;
+        for (from, to) in [(start, end), (end, start), (start, end)] {
+            let Some(found) = maze.route(from, to, time, true) else {
+                return;
+            };
+
+            for (minute, (expedition, _)) in found.path.unwrap_or_default().into_iter().enumerate()
+            {
+                let t = time + minute;
+                let mut frame = Frame::new(Coord::new(0, 0), corner);
+                frame.set_caption(format!("minute {t}"));
+                for (p, cell) in maze.0.iter() {
+                    let blizzards: Vec<Direction> = maze.blizzards_at(p, t).collect();
+                    let (c, colour) = match (cell, blizzards.len()) {
+                        (Cell::Wall, _) => ('#', Colour::Grey),
+                        (_, 0) => ('.', Colour::Grey),
+                        (_, 1) => (
+                            blizzards[0].to_string().chars().next().unwrap(),
+                            Colour::Cyan,
+                        ),
+                        (_, n) => (char::from_digit(n as u32, 10).unwrap(), Colour::Blue),
+                    };
+                    frame.set(coord(p), c, colour);
+                }
+                frame.set(coord(expedition), 'E', Colour::Red);
+                screen.show(&frame);
+            }
+
+            time += found.cost;
+        }
+    }
+}
@@ -0,0 +1,227 @@
+//! Day 25: Full of Hot Air. Adding up [`Fuel`] written in SNAFU numbers.
+use aoc_common::{debug, parse_lines, trace, Answer, ParseError, Result, Solution};
+use std::{
+    fmt::{self},
+    str::FromStr,
+};
+
+/// A SNAFU digit.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Base5 {
+    #[default]
+    Two,
+    One,
+    Zero,
+    Minus,
+    Equal,
+}
+
+impl Base5 {
+    pub fn value(&self) -> i64 {
+        match *self {
+            Self::Two => 2,
+            Self::One => 1,
+            Self::Zero => 0,
+            Self::Minus => -1,
+            Self::Equal => -2,
+        }
+    }
+}
+
+impl TryFrom<char> for Base5 {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> std::result::Result<Self, Self::Error> {
+        match c {
+            '2' => Ok(Self::Two),
+            '1' => Ok(Self::One),
+            '0' => Ok(Self::Zero),
+            '-' => Ok(Self::Minus),
+            '=' => Ok(Self::Equal),
+            _ => Err(ParseError::new("", "", "a SNAFU digit, 2 1 0 - or =")),
+        }
+    }
+}
+
+impl From<i64> for Base5 {
+    fn from(d: i64) -> Self {
+        match d {
+            2 => Self::Two,
+            1 => Self::One,
+            0 => Self::Zero,
+            4 => Self::Minus,
+            3 => Self::Equal,
+            _ => panic!("unrecognized: {d}"),
+        }
+    }
+}
+
+/// A fuel requirement in SNAFU, balanced base five, most significant digit
+/// first.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Fuel(Vec<Base5>);
+
+impl Fuel {
+    pub fn digits(&self) -> &[Base5] {
+        &self.0
+    }
+
+    pub fn to_decimal(&self) -> i64 {
+        self.0.iter().fold(0, |dec, b| dec * 5 + b.value())
+    }
+
+    /// The SNAFU form of a number that isn't negative.
+    pub fn from_decimal(d: i64) -> Fuel {
+        // let mut base: Vec<Base5> = vec![];
+        let mut base: Vec<i64> = vec![];
+        let mut v = d;
+        while v > 0 {
+            let rem = v % 5;
+            base.push(rem);
+            v /= 5;
+        }
+
+        // println!("{}", 5i32.pow(place - 1));
+
+        // let mut q = d;
+        // for i in (0..place).rev() {
+        //     let e = 5i32.pow(i);
+        //     // q = q / e;
+        //     println!("{}", q / e);
+        //     // println!("{}", q % e);
+        //     q %= e;
+        // }
+
+        // base.reverse();
+        trace!("{base:?}");
+
+        let mut carry = false;
+        let mut snafu = String::new();
+        for mut c in base {
+            if carry {
+                c += 1;
+            }
+            carry = false;
+            match c {
+                3 => {
+                    carry = true;
+                    snafu.push('=');
+                }
+                4 => {
+                    carry = true;
+                    snafu.push('-');
+                }
+                5 => {
+                    carry = true;
+                    snafu.push('0');
+                }
+                0 => snafu.push('0'),
+                1 => snafu.push('1'),
+                2 => snafu.push('2'),
+                _ => panic!("should not get here: {}", c),
+            }
+        }
+        // a carry out of the top digit is a new one, and zero still needs a
+        // digit of its own
+        if carry || snafu.is_empty() {
+            snafu.push(if carry { '1' } else { '0' });
+        }
+        // only SNAFU digits are pushed above
+        Self(snafu.chars().rev().map(|c| c.try_into().unwrap()).collect())
+    }
+}
+
+impl fmt::Display for Fuel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for b in &self.0 {
+            match b {
+                Base5::Two => write!(f, "2"),
+                Base5::One => write!(f, "1"),
+                Base5::Zero => write!(f, "0"),
+                Base5::Minus => write!(f, "-"),
+                Base5::Equal => write!(f, "="),
+            }?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Fuel {
+    type Err = ParseError;
+
+    fn from_str(item: &str) -> std::result::Result<Self, Self::Err> {
+        if item.is_empty() {
+            return Err(ParseError::missing(item, "a SNAFU number"));
+        }
+
+        item.char_indices()
+            .map(|(i, c)| {
+                Base5::try_from(c)
+                    .map_err(|e| ParseError::new(item, &item[i..i + c.len_utf8()], e.expected))
+            })
+            .collect::<std::result::Result<_, _>>()
+            .map(Self)
+    }
+}
+
+pub struct Day25 {
+    requirements: Vec<Fuel>,
+}
+
+impl Day25 {
+    pub fn requirements(&self) -> &[Fuel] {
+        &self.requirements
+    }
+}
+
+impl Solution for Day25 {
+    fn parse(input: &str) -> Result<Self> {
+        let requirements = parse_lines(input)?;
+        Ok(Self { requirements })
+    }
+
+    fn part1(&self) -> Answer {
+        let mut sum = 0;
+        for r in &self.requirements {
+            sum += r.to_decimal();
+        }
+
+        debug!("{sum}");
+        let snafu = Fuel::from_decimal(sum);
+        snafu.to_string().into()
+    }
+
+    fn part2(&self) -> Answer {
+        // day 25 only has the one puzzle
+        Answer::Unsolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::testing::{check, fuzz};
+
+    #[test]
+    fn test_fuel() {
+        check(
+            500,
+            |rng| rng.range(0..=1 << 60),
+            |n| {
+                assert_eq!(Fuel::from_decimal(*n).to_decimal(), *n);
+            },
+        );
+
+        check(
+            500,
+            |rng| rng.string("210-=", 1..=20),
+            |s| {
+                assert_eq!(s.parse::<Fuel>().unwrap().to_string(), *s);
+            },
+        );
+
+        fuzz(2000, &["1=-0-2", "2=-01"], |item| {
+            let _ = item.parse::<Fuel>();
+        });
+    }
+}